@@ -0,0 +1,64 @@
+use core::fmt::{self, Debug, Formatter};
+
+use crate::ShortCryptError;
+
+/// A user-defined alphabet with 32 or 64 distinct ASCII characters, used by `encrypt_to_custom_alphabet` and `decrypt_custom_alphabet`. A 32-character alphabet encodes data like Base32, and a 64-character alphabet encodes data like Base64.
+#[derive(Clone)]
+pub struct Alphabet {
+    pub(crate) symbols:        [u8; 64],
+    pub(crate) len:            usize,
+    pub(crate) decoding_table: [u8; 256],
+}
+
+impl Debug for Alphabet {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(Alphabet, f, self, let .symbols = core::str::from_utf8(self.as_bytes()).unwrap());
+    }
+}
+
+impl Alphabet {
+    /// Create a new alphabet. The characters must be 32 or 64 distinct, visible ASCII characters.
+    pub fn new<S: AsRef<str>>(characters: S) -> Result<Alphabet, ShortCryptError> {
+        let bytes = characters.as_ref().as_bytes();
+        let len = bytes.len();
+
+        if len != 32 && len != 64 {
+            return Err(ShortCryptError::InvalidAlphabet);
+        }
+
+        let mut symbols = [0u8; 64];
+        let mut decoding_table = [0xFF; 256];
+
+        for (i, n) in bytes.iter().copied().enumerate() {
+            if !n.is_ascii_graphic() {
+                return Err(ShortCryptError::InvalidAlphabet);
+            }
+
+            if decoding_table[n as usize] != 0xFF {
+                return Err(ShortCryptError::InvalidAlphabet);
+            }
+
+            symbols[i] = n;
+            decoding_table[n as usize] = i as u8;
+        }
+
+        Ok(Alphabet {
+            symbols,
+            len,
+            decoding_table,
+        })
+    }
+
+    /// Get the characters of this alphabet.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.symbols[..self.len]
+    }
+
+    /// The number of bits that a character carries. It is 5 or 6.
+    #[inline]
+    pub(crate) fn bits(&self) -> u32 {
+        self.len.trailing_zeros()
+    }
+}
@@ -0,0 +1,67 @@
+//! Ascii85 conversion (without the `z` abbreviation and the `<~`, `~>` delimiters) used by the Base85 text format.
+
+use alloc::{string::String, vec::Vec};
+
+pub(crate) const ALPHABET: [u8; 85] = {
+    let mut alphabet = [0; 85];
+
+    let mut i = 0;
+
+    while i < 85 {
+        alphabet[i] = b'!' + i as u8;
+
+        i += 1;
+    }
+
+    alphabet
+};
+
+/// Encode `data` and push the symbols to `output`. A final group of `n` bytes becomes `n + 1` symbols.
+pub(crate) fn encode(data: &[u8], output: &mut String) {
+    output.reserve((data.len() * 5).div_ceil(4));
+
+    for chunk in data.chunks(4) {
+        let mut group = [0u8; 4];
+
+        group[..chunk.len()].copy_from_slice(chunk);
+
+        let mut value = u32::from_be_bytes(group);
+
+        let mut symbols = [0u8; 5];
+
+        for symbol in symbols.iter_mut().rev() {
+            *symbol = ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+
+        for symbol in symbols[..=chunk.len()].iter().copied() {
+            output.push(symbol as char);
+        }
+    }
+}
+
+/// Decode symbol values back to bytes. Returns `None` if the number of symbols is impossible or a group overflows.
+pub(crate) fn decode(digits: &[u8]) -> Option<Vec<u8>> {
+    if digits.len() % 5 == 1 {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(digits.len() * 4 / 5);
+
+    for chunk in digits.chunks(5) {
+        let mut value = 0u64;
+
+        for i in 0..5 {
+            // pad a final partial group with the largest symbol
+            value = value * 85 + u64::from(chunk.get(i).copied().unwrap_or(84));
+        }
+
+        if value > u64::from(u32::MAX) {
+            return None;
+        }
+
+        result.extend_from_slice(&(value as u32).to_be_bytes()[..(chunk.len() - 1)]);
+    }
+
+    Some(result)
+}
@@ -0,0 +1,51 @@
+//! Base45 conversion (RFC 9285) used by the Base45 text format.
+
+use alloc::{string::String, vec::Vec};
+
+pub(crate) const ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Encode `data` and push the symbols to `output`. Every two bytes become three symbols, and a final single byte becomes two symbols.
+pub(crate) fn encode(data: &[u8], output: &mut String) {
+    output.reserve((data.len() * 3).div_ceil(2));
+
+    for chunk in data.chunks(2) {
+        let (mut value, width) = match chunk {
+            [a, b] => ((u32::from(*a) << 8) | u32::from(*b), 3),
+            _ => (u32::from(chunk[0]), 2),
+        };
+
+        for _ in 0..width {
+            output.push(ALPHABET[(value % 45) as usize] as char);
+            value /= 45;
+        }
+    }
+}
+
+/// Decode symbol values back to bytes. Returns `None` if the number of symbols is impossible or a group overflows.
+pub(crate) fn decode(digits: &[u8]) -> Option<Vec<u8>> {
+    if digits.len() % 3 == 1 {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(digits.len() * 2 / 3);
+
+    for chunk in digits.chunks(3) {
+        let value = chunk.iter().rev().fold(0u32, |value, d| value * 45 + u32::from(*d));
+
+        if chunk.len() == 3 {
+            if value > 0xFFFF {
+                return None;
+            }
+
+            result.extend_from_slice(&(value as u16).to_be_bytes());
+        } else {
+            if value > 0xFF {
+                return None;
+            }
+
+            result.push(value as u8);
+        }
+    }
+
+    Some(result)
+}
@@ -0,0 +1,11 @@
+/// Where the symbol of the **base** is placed in an encrypted text, set by `ShortCryptBuilder::base_placement`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BasePlacement {
+    /// At a position derived from the key and the whole text, so the base cannot be told apart from the **body**. This is the default.
+    #[default]
+    Keyed,
+    /// Before the **body**, so texts with the same base share their first symbol.
+    Start,
+    /// After the **body**.
+    End,
+}
@@ -0,0 +1,55 @@
+//! The BCH checksum of Bech32 (BIP-0173).
+
+pub(crate) const ALPHABET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] = [0x3B6A57B2, 0x26508E6D, 0x1EA119FA, 0x3D4233DD, 0x2A1462B3];
+
+fn polymod<I: Iterator<Item = u8>>(values: I) -> u32 {
+    let mut checksum = 1u32;
+
+    for v in values {
+        let b = checksum >> 25;
+
+        checksum = ((checksum & 0x1FFFFFF) << 5) ^ u32::from(v);
+
+        for (i, g) in GENERATOR.iter().copied().enumerate() {
+            if (b >> i) & 1 == 1 {
+                checksum ^= g;
+            }
+        }
+    }
+
+    checksum
+}
+
+#[inline]
+fn expand_hrp(hrp: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    hrp.iter().map(|n| n >> 5).chain(core::iter::once(0)).chain(hrp.iter().map(|n| n & 0x1F))
+}
+
+/// Check whether `hrp` is a valid lowercase human-readable part.
+#[inline]
+pub(crate) fn is_valid_hrp(hrp: &[u8]) -> bool {
+    (1..=83).contains(&hrp.len())
+        && hrp.iter().all(|n| (33..=126).contains(n) && !n.is_ascii_uppercase())
+}
+
+/// Create the checksum values of the lowercase `hrp` and the data values.
+pub(crate) fn create_checksum(hrp: &[u8], data: &[u8]) -> [u8; 6] {
+    let checksum =
+        polymod(expand_hrp(hrp).chain(data.iter().copied()).chain([0; 6].iter().copied())) ^ 1;
+
+    let mut result = [0u8; 6];
+
+    for (i, n) in result.iter_mut().enumerate() {
+        *n = ((checksum >> (5 * (5 - i))) & 0x1F) as u8;
+    }
+
+    result
+}
+
+/// Verify the checksum of the lowercase `hrp` and the data values (including the checksum values).
+#[inline]
+pub(crate) fn verify_checksum(hrp: &[u8], data: &[u8]) -> bool {
+    polymod(expand_hrp(hrp).chain(data.iter().copied())) == 1
+}
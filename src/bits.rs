@@ -0,0 +1,135 @@
+//! Bit-group conversion (MSB first, without padding) used by the Base32-like and hexadecimal text formats.
+
+use alloc::{string::String, vec::Vec};
+
+/// An iterator over the symbols of data encoded with an alphabet whose length is `2^bits`.
+pub(crate) struct Symbols<'a> {
+    data:        core::slice::Iter<'a, u8>,
+    alphabet:    &'a [u8],
+    bits:        u32,
+    buffer:      u32,
+    buffer_bits: u32,
+}
+
+impl<'a> Symbols<'a> {
+    #[inline]
+    pub(crate) fn new(data: &'a [u8], alphabet: &'a [u8], bits: u32) -> Symbols<'a> {
+        Symbols {
+            data: data.iter(),
+            alphabet,
+            bits,
+            buffer: 0,
+            buffer_bits: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Symbols<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let mask = (1u32 << self.bits) - 1;
+
+        if self.buffer_bits < self.bits {
+            match self.data.next() {
+                Some(n) => {
+                    self.buffer = (self.buffer << 8) | u32::from(*n);
+                    self.buffer_bits += 8;
+                },
+                None => {
+                    if self.buffer_bits == 0 {
+                        return None;
+                    }
+
+                    let v = (self.buffer << (self.bits - self.buffer_bits)) & mask;
+
+                    self.buffer_bits = 0;
+
+                    return Some(self.alphabet[v as usize]);
+                },
+            }
+        }
+
+        self.buffer_bits -= self.bits;
+
+        Some(self.alphabet[((self.buffer >> self.buffer_bits) & mask) as usize])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let bits = self.bits as usize;
+        let len = (self.data.len() * 8 + self.buffer_bits as usize).div_ceil(bits);
+
+        (len, Some(len))
+    }
+}
+
+/// Encode `data` with `alphabet` whose length is `2^bits`, and push the symbols to `output`.
+pub(crate) fn encode(data: &[u8], alphabet: &[u8], bits: u32, output: &mut String) {
+    output.reserve((data.len() * 8).div_ceil(bits as usize));
+
+    for n in Symbols::new(data, alphabet, bits) {
+        output.push(n as char);
+    }
+}
+
+/// Check whether `len` symbols (each takes `bits` bits) can be created by `encode`.
+#[inline]
+pub(crate) fn is_valid_len(len: usize, bits: u32) -> bool {
+    (len * bits as usize) % 8 < bits as usize
+}
+
+/// Check the symbol values (each takes `bits` bits) of an encoded **body**, where `0xFF` marks a symbol outside the alphabet. If `strict`, the number of values must also be one `encode` can create, and the unused bits of the last value must be zero, so every **body** has only one text.
+pub(crate) fn is_valid_body<I: Iterator<Item = u8>>(values: I, bits: u32, strict: bool) -> bool {
+    let mut len = 0;
+    let mut last = 0;
+
+    for v in values {
+        if v == 0xFF {
+            return false;
+        }
+
+        len += 1;
+        last = v;
+    }
+
+    if strict {
+        let unused_bits = (len * bits as usize) % 8;
+
+        if unused_bits >= bits as usize || last & ((1 << unused_bits) - 1) != 0 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Decode symbol values (each takes `bits` bits) back to bytes, and pass every byte to `push`. The values should be checked by `is_valid_body` first.
+pub(crate) fn decode_iter<I: Iterator<Item = u8>, F: FnMut(u8)>(digits: I, bits: u32, mut push: F) {
+    let mut buffer = 0u32;
+    let mut buffer_bits = 0;
+
+    for d in digits {
+        buffer = (buffer << bits) | u32::from(d);
+        buffer_bits += bits;
+
+        if buffer_bits >= 8 {
+            buffer_bits -= 8;
+
+            push((buffer >> buffer_bits) as u8);
+        }
+    }
+}
+
+/// Decode symbol values (each takes `bits` bits) back to bytes. Returns `None` if the number of symbols is impossible.
+pub(crate) fn decode(digits: &[u8], bits: u32) -> Option<Vec<u8>> {
+    if !is_valid_len(digits.len(), bits) {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(digits.len() * bits as usize / 8);
+
+    decode_iter(digits.iter().copied(), bits, |n| result.push(n));
+
+    Some(result)
+}
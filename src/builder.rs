@@ -0,0 +1,69 @@
+use crate::{BasePlacement, Format, ShortCrypt};
+
+/// Create a configured `ShortCrypt` instance, created by `ShortCrypt::builder`.
+///
+/// Besides the key (or an exported state), the builder sets the default text format used by `ShortCrypt::encrypt_to_text` and `ShortCrypt::decrypt_text`, where the base symbol is placed, whether Base64 texts are padded, whether the plaintext is checked against its base when decrypting, and whether separators are ignored when decoding. Texts are only compatible between instances with the same base placement and padding.
+#[derive(Debug, Clone)]
+pub struct ShortCryptBuilder {
+    short_crypt: ShortCrypt,
+}
+
+impl ShortCryptBuilder {
+    #[inline]
+    pub(crate) fn new(short_crypt: ShortCrypt) -> ShortCryptBuilder {
+        ShortCryptBuilder {
+            short_crypt,
+        }
+    }
+
+    /// Start from a state exported by `ShortCrypt::to_state` instead of a key.
+    #[inline]
+    pub fn from_state(state: [u8; 16]) -> ShortCryptBuilder {
+        Self::new(ShortCrypt::from_state(state))
+    }
+
+    /// The format used by `ShortCrypt::encrypt_to_text` and `ShortCrypt::decrypt_text`. The default value is `Format::UrlComponent`.
+    #[inline]
+    pub fn format(mut self, format: Format) -> ShortCryptBuilder {
+        self.short_crypt.format = format;
+
+        self
+    }
+
+    /// Where the base symbol is placed in an encrypted text. The default value is `BasePlacement::Keyed`.
+    #[inline]
+    pub fn base_placement(mut self, base_placement: BasePlacement) -> ShortCryptBuilder {
+        self.short_crypt.base_placement = base_placement;
+
+        self
+    }
+
+    /// Whether `ShortCrypt::encrypt_to_base64` appends the `=` padding and `ShortCrypt::decrypt_base64` requires it. The default value is `true`.
+    #[inline]
+    pub fn padding(mut self, padding: bool) -> ShortCryptBuilder {
+        self.short_crypt.padding = padding;
+
+        self
+    }
+
+    /// Whether decrypting checks that the CRC-8 of the plaintext matches the base, so that most tampered or mistyped texts return `ShortCryptError::InvalidChecksum` instead of a wrong plaintext. Since the base only has 32 values, about one in 32 wrong texts still passes. The default value is `false`.
+    #[inline]
+    pub fn checksum(mut self, checksum: bool) -> ShortCryptBuilder {
+        self.short_crypt.checksum = checksum;
+
+        self
+    }
+
+    /// Whether the `decrypt_*` methods for texts should ignore separators, the lenient decoding mode described in `ShortCrypt::set_ignore_separators`. The default value is `false`.
+    #[inline]
+    pub fn ignore_separators(mut self, ignore_separators: bool) -> ShortCryptBuilder {
+        self.short_crypt.set_ignore_separators(ignore_separators);
+
+        self
+    }
+
+    #[inline]
+    pub fn build(self) -> ShortCrypt {
+        self.short_crypt
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+
+use crate::varint;
+
+/// A set of permissions or feature flags (a `u64` bitmask) granted to a subject (e.g. a user ID), which can be encrypted into a capability token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Capability {
+    pub subject: u64,
+    pub flags:   u64,
+}
+
+impl Capability {
+    #[inline]
+    pub const fn new(subject: u64, flags: u64) -> Capability {
+        Capability {
+            subject,
+            flags,
+        }
+    }
+
+    /// Whether all the bits of `flags` are set.
+    #[inline]
+    pub const fn contains(&self, flags: u64) -> bool {
+        self.flags & flags == flags
+    }
+
+    /// Pack the subject and the flags as two LEB128 integers.
+    pub(crate) fn to_bytes(self) -> Vec<u8> {
+        let (subject, subject_len) = varint::encode(self.subject);
+        let (flags, flags_len) = varint::encode(self.flags);
+
+        let mut bytes = Vec::with_capacity(subject_len + flags_len);
+
+        bytes.extend_from_slice(&subject[..subject_len]);
+        bytes.extend_from_slice(&flags[..flags_len]);
+
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Capability> {
+        let (subject, subject_len) = varint::decode_prefix(bytes)?;
+        let flags = varint::decode(&bytes[subject_len..])?;
+
+        Some(Capability {
+            subject,
+            flags,
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::ShortCrypt;
+
+/// An iterator over the characters of an encrypted text, created by `ShortCrypt::encrypt_to_url_component_chars`, `ShortCrypt::encrypt_to_qr_code_alphanumeric_chars` or `ShortCrypt::encrypt_to_custom_alphabet_chars`. The characters are encoded lazily, so the whole text is never materialized.
+#[derive(Debug, Clone)]
+pub struct EncryptedChars<'a> {
+    body:       Vec<u8>,
+    alphabet:   &'a [u8],
+    bits:       u32,
+    base_char:  char,
+    base_index: usize,
+    /// The number of symbols of the encoded **body**.
+    body_len:   usize,
+    /// The index of the next character in the whole text.
+    index:      usize,
+}
+
+impl<'a> EncryptedChars<'a> {
+    /// `alphabet` must have `2^bits` ASCII symbols, and `bits` must not be larger than 8.
+    pub(crate) fn new(
+        body: Vec<u8>,
+        alphabet: &'a [u8],
+        bits: u32,
+        base_char: char,
+        short_crypt: &ShortCrypt,
+    ) -> EncryptedChars<'a> {
+        let body_len = (body.len() * 8).div_ceil(bits as usize);
+
+        let mut chars = EncryptedChars {
+            body,
+            alphabet,
+            bits,
+            base_char,
+            base_index: 0,
+            body_len,
+            index: 0,
+        };
+
+        let mut sum = base_char as u64;
+
+        for i in 0..body_len {
+            sum = sum.wrapping_add(u64::from(chars.symbol(i)));
+        }
+
+        chars.base_index = short_crypt.base_index(sum, body_len + 1);
+
+        chars
+    }
+
+    /// Get the `i`-th symbol of the encoded **body**.
+    #[inline]
+    fn symbol(&self, i: usize) -> u8 {
+        let bit_index = i * self.bits as usize;
+        let byte_index = bit_index / 8;
+
+        let high = u16::from(self.body[byte_index]);
+        let low = u16::from(self.body.get(byte_index + 1).copied().unwrap_or(0));
+
+        let shift = 16 - self.bits - (bit_index % 8) as u32;
+        let mask = (1u16 << self.bits) - 1;
+
+        self.alphabet[((((high << 8) | low) >> shift) & mask) as usize]
+    }
+}
+
+impl<'a> Iterator for EncryptedChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let index = self.index;
+
+        if index > self.body_len {
+            return None;
+        }
+
+        self.index += 1;
+
+        match index.cmp(&self.base_index) {
+            core::cmp::Ordering::Less => Some(self.symbol(index) as char),
+            core::cmp::Ordering::Equal => Some(self.base_char),
+            core::cmp::Ordering::Greater => Some(self.symbol(index - 1) as char),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.body_len + 1 - self.index.min(self.body_len + 1);
+
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for EncryptedChars<'a> {}
+
+impl<'a> FusedIterator for EncryptedChars<'a> {}
@@ -0,0 +1,149 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{QrCodeAlphanumericEncoding, ShortCrypt, ShortCryptError, UrlComponentEncoding};
+
+/// A cipher created by `ShortCrypt::encrypt`, separated into a **base** and a **body**. The **base** is always in the range of `0` to `31`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "CipherData")
+)]
+pub struct Cipher {
+    pub(crate) base: u8,
+    pub(crate) body: Vec<u8>,
+}
+
+impl Cipher {
+    /// Create a cipher from its **base** and **body**. The **base** must not be larger than `31`.
+    #[inline]
+    pub fn new(base: u8, body: Vec<u8>) -> Result<Cipher, ShortCryptError> {
+        if base > 31 {
+            return Err(ShortCryptError::InvalidBase);
+        }
+
+        Ok(Cipher {
+            base,
+            body,
+        })
+    }
+
+    #[inline]
+    pub const fn base(&self) -> u8 {
+        self.base
+    }
+
+    #[inline]
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Borrow this cipher as a `CipherRef`.
+    #[inline]
+    pub fn as_cipher_ref(&self) -> CipherRef<'_> {
+        CipherRef::from(self)
+    }
+
+    /// Separate this cipher into its **base** and **body**.
+    #[inline]
+    pub fn into_parts(self) -> (u8, Vec<u8>) {
+        (self.base, self.body)
+    }
+
+    /// Encode this cipher in the format of `ShortCrypt::encrypt_to_url_component`. The position of the **base** depends on the key of `short_crypt`, which must be the one that created this cipher.
+    #[inline]
+    pub fn to_url_component(&self, short_crypt: &ShortCrypt) -> String {
+        let mut result = String::with_capacity(ShortCrypt::url_component_len(self.body.len()));
+
+        short_crypt.encode_cipher(self.base, &self.body, &UrlComponentEncoding, &mut result);
+
+        result
+    }
+
+    /// Encode this cipher in the format of `ShortCrypt::encrypt_to_qr_code_alphanumeric`. The position of the **base** depends on the key of `short_crypt`, which must be the one that created this cipher.
+    #[inline]
+    pub fn to_qr_code_alphanumeric(&self, short_crypt: &ShortCrypt) -> String {
+        let mut result =
+            String::with_capacity(ShortCrypt::qr_code_alphanumeric_len(self.body.len()));
+
+        short_crypt.encode_cipher(self.base, &self.body, &QrCodeAlphanumericEncoding, &mut result);
+
+        result
+    }
+}
+
+/// A borrowed cipher, whose **body** is a slice owned elsewhere. It can be passed to `ShortCrypt::decrypt` without copying the **body** into a `Cipher`. The **base** is always in the range of `0` to `31`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CipherRef<'a>(pub(crate) u8, pub(crate) &'a [u8]);
+
+impl<'a> CipherRef<'a> {
+    /// Create a borrowed cipher from its **base** and **body**. The **base** must not be larger than `31`.
+    #[inline]
+    pub const fn new(base: u8, body: &'a [u8]) -> Result<CipherRef<'a>, ShortCryptError> {
+        if base > 31 {
+            return Err(ShortCryptError::InvalidBase);
+        }
+
+        Ok(CipherRef(base, body))
+    }
+
+    #[inline]
+    pub const fn base(&self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn body(&self) -> &'a [u8] {
+        self.1
+    }
+
+    /// Copy the **body** to create an owned `Cipher`.
+    #[inline]
+    pub fn to_cipher(&self) -> Cipher {
+        Cipher {
+            base: self.0, body: self.1.to_vec()
+        }
+    }
+}
+
+impl<'a> From<&'a Cipher> for CipherRef<'a> {
+    #[inline]
+    fn from(cipher: &'a Cipher) -> CipherRef<'a> {
+        CipherRef(cipher.base, &cipher.body)
+    }
+}
+
+/// The unchecked fields of a deserialized `Cipher`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Cipher")]
+struct CipherData {
+    base: u8,
+    body: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CipherData> for Cipher {
+    type Error = ShortCryptError;
+
+    #[inline]
+    fn try_from(data: CipherData) -> Result<Cipher, ShortCryptError> {
+        Cipher::new(data.base, data.body)
+    }
+}
+
+impl TryFrom<(u8, Vec<u8>)> for Cipher {
+    type Error = ShortCryptError;
+
+    #[inline]
+    fn try_from((base, body): (u8, Vec<u8>)) -> Result<Cipher, ShortCryptError> {
+        Cipher::new(base, body)
+    }
+}
+
+impl From<Cipher> for (u8, Vec<u8>) {
+    #[inline]
+    fn from(cipher: Cipher) -> (u8, Vec<u8>) {
+        cipher.into_parts()
+    }
+}
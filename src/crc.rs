@@ -0,0 +1,52 @@
+//! A `const` implementation of CRC-64/WE, the same as `CRCu64::crc64we`, so keys can be derived at compile time, and a table of CRC-8/CDMA2000 for computing the CRC-8 of many plaintexts side by side.
+
+const POLY: u64 = 0x42F0_E1EB_A9EA_3693;
+
+pub(crate) const fn crc64we(data: &[u8]) -> u64 {
+    let mut crc = u64::MAX;
+
+    let mut i = 0;
+
+    while i < data.len() {
+        crc ^= (data[i] as u64) << 56;
+
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & (1 << 63) != 0 { (crc << 1) ^ POLY } else { crc << 1 };
+
+            bit += 1;
+        }
+
+        i += 1;
+    }
+
+    crc ^ u64::MAX
+}
+
+const CRC8_POLY: u8 = 0x9B;
+
+/// The table of CRC-8/CDMA2000 (the initial value is `0xFF`), the same as `CRCu8::crc8cdma2000`. For a CRC `crc` and a byte `n`, the next CRC is `CRC8_CDMA2000_TABLE[(crc ^ n) as usize]`.
+pub(crate) const CRC8_CDMA2000_TABLE: [u8; 256] = {
+    let mut table = [0; 256];
+
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u8;
+
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ CRC8_POLY } else { crc << 1 };
+
+            bit += 1;
+        }
+
+        table[i] = crc;
+
+        i += 1;
+    }
+
+    table
+};
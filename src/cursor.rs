@@ -0,0 +1,89 @@
+//! Opaque pagination cursors, which can be used by REST APIs instead of exposing raw offsets.
+//!
+//! A `Cursor` is packed into a compact binary form (the offset and the limit as LEB128 integers, followed by the sort key) and encrypted into a URL component by `ShortCrypt::encrypt_cursor`.
+//!
+//! ```rust
+//! use short_crypt::{cursor::Cursor, ShortCrypt};
+//!
+//! let sc = ShortCrypt::new("magickey");
+//!
+//! let cursor = Cursor::new(40, 20).with_sort_key("created_at");
+//!
+//! let token = sc.encrypt_cursor(&cursor);
+//!
+//! assert_eq!(cursor, sc.decrypt_cursor(&token).unwrap());
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use crate::varint;
+
+/// The position of a page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Cursor {
+    /// The number of items before the page.
+    pub offset:   u64,
+    /// The maximum number of items in the page.
+    pub limit:    u64,
+    /// The key (and/or the last seen value) used to sort the items. It can be empty.
+    pub sort_key: String,
+}
+
+impl Cursor {
+    /// Create a cursor without a sort key.
+    #[inline]
+    pub fn new(offset: u64, limit: u64) -> Cursor {
+        Cursor {
+            offset,
+            limit,
+            sort_key: String::new(),
+        }
+    }
+
+    /// Set the sort key.
+    #[inline]
+    pub fn with_sort_key<S: Into<String>>(mut self, sort_key: S) -> Cursor {
+        self.sort_key = sort_key.into();
+
+        self
+    }
+
+    /// Get the cursor of the next page.
+    #[inline]
+    pub fn next_page(&self) -> Cursor {
+        Cursor {
+            offset:   self.offset.saturating_add(self.limit),
+            limit:    self.limit,
+            sort_key: self.sort_key.clone(),
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let (offset, offset_len) = varint::encode(self.offset);
+        let (limit, limit_len) = varint::encode(self.limit);
+
+        let mut bytes = Vec::with_capacity(offset_len + limit_len + self.sort_key.len());
+
+        bytes.extend_from_slice(&offset[..offset_len]);
+        bytes.extend_from_slice(&limit[..limit_len]);
+        bytes.extend_from_slice(self.sort_key.as_bytes());
+
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Cursor> {
+        let (offset, offset_len) = varint::decode_prefix(bytes)?;
+        let bytes = &bytes[offset_len..];
+
+        let (limit, limit_len) = varint::decode_prefix(bytes)?;
+        let bytes = &bytes[limit_len..];
+
+        let sort_key = String::from_utf8(bytes.to_vec()).ok()?;
+
+        Some(Cursor {
+            offset,
+            limit,
+            sort_key,
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::ShortCrypt;
+
+/// A lazily encrypted URL component, created by `ShortCrypt::display_url_component`. The data is encrypted and encoded while it is being formatted, so no intermediate `String` is allocated for the text.
+#[derive(Debug)]
+pub struct DisplayUrlComponent<'a, T: ?Sized> {
+    short_crypt: &'a ShortCrypt,
+    data:        &'a T,
+}
+
+impl<'a, T: ?Sized> DisplayUrlComponent<'a, T> {
+    #[inline]
+    pub(crate) const fn new(
+        short_crypt: &'a ShortCrypt,
+        data: &'a T,
+    ) -> DisplayUrlComponent<'a, T> {
+        DisplayUrlComponent {
+            short_crypt,
+            data,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Clone for DisplayUrlComponent<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for DisplayUrlComponent<'a, T> {}
+
+impl<'a, T: ?Sized + AsRef<[u8]>> Display for DisplayUrlComponent<'a, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        self.short_crypt.encrypt_to_url_component_writer(self.data, f)
+    }
+}
+
+/// A lazily encrypted QR code alphanumeric text, created by `ShortCrypt::display_qr_code_alphanumeric`. The data is encrypted and encoded while it is being formatted, so no intermediate `String` is allocated for the text.
+#[derive(Debug)]
+pub struct DisplayQrCodeAlphanumeric<'a, T: ?Sized> {
+    short_crypt: &'a ShortCrypt,
+    data:        &'a T,
+}
+
+impl<'a, T: ?Sized> DisplayQrCodeAlphanumeric<'a, T> {
+    #[inline]
+    pub(crate) const fn new(
+        short_crypt: &'a ShortCrypt,
+        data: &'a T,
+    ) -> DisplayQrCodeAlphanumeric<'a, T> {
+        DisplayQrCodeAlphanumeric {
+            short_crypt,
+            data,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> Clone for DisplayQrCodeAlphanumeric<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for DisplayQrCodeAlphanumeric<'a, T> {}
+
+impl<'a, T: ?Sized + AsRef<[u8]>> Display for DisplayQrCodeAlphanumeric<'a, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        self.short_crypt.encrypt_to_qr_code_alphanumeric_writer(self.data, f)
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{bits, radix, ShortCryptError, BASE32_DECODING_TABLE, URL_COMPONENT_DECODING_TABLE};
+
+/// The symbols of bases used by the built-in encodings. The base `n` is represented by the `n`-th symbol.
+pub(crate) const BASE_SYMBOLS: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+pub(crate) const BASE_DECODING_TABLE: [u8; 256] = radix::decoding_table(BASE_SYMBOLS);
+
+pub(crate) const URL_COMPONENT_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const QR_CODE_ALPHANUMERIC_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The RFC 4648 Base32 alphabet which is used by the **body** of a QR code alphanumeric text.
+pub(crate) const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// A text encoding for ciphers, used by `ShortCrypt::encrypt_to` and `ShortCrypt::decrypt_from`. The **body** is encoded by `encode`, and the **base** is represented by one ASCII symbol which is inserted into the encoded **body** at a position derived from the key.
+pub trait OutputEncoding {
+    /// All the symbols which may appear in an encoded text, including the base symbols.
+    fn alphabet(&self) -> &[u8];
+
+    /// Encode the **body** of a cipher and push the text to `output`.
+    fn encode(&self, data: &[u8], output: &mut String);
+
+    /// Decode a text created by `encode`. Returns `None` if the text is incorrect.
+    fn decode(&self, text: &[u8]) -> Option<Vec<u8>>;
+
+    /// Get the ASCII symbol which represents the **base** (`0`–`31`).
+    fn base_to_symbol(&self, base: u8) -> u8;
+
+    /// Get the **base** represented by an ASCII symbol. Returns `None` if the symbol does not represent a base.
+    fn symbol_to_base(&self, symbol: u8) -> Option<u8>;
+}
+
+/// An `OutputEncoding` whose **body** symbols take `BITS` bits each, so a text can be decoded around the base symbol without being copied first.
+pub(crate) trait BitsEncoding: OutputEncoding {
+    const DECODING_TABLE: &'static [u8; 256];
+    const BITS: u32;
+    /// Whether the length of the **body** must be one `encode` can create and its unused bits must be zero. Otherwise, the extra bits are ignored.
+    const STRICT: bool;
+    /// The error for a **body** which cannot be decoded.
+    const ERROR: ShortCryptError;
+}
+
+/// The encoding used by `encrypt_to_url_component` and `decrypt_url_component`. The **body** is encoded with Base64-URL without padding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UrlComponentEncoding;
+
+impl OutputEncoding for UrlComponentEncoding {
+    #[inline]
+    fn alphabet(&self) -> &[u8] {
+        URL_COMPONENT_ALPHABET
+    }
+
+    #[inline]
+    fn encode(&self, data: &[u8], output: &mut String) {
+        base64_url::encode_to_string(data, output);
+    }
+
+    #[inline]
+    fn decode(&self, text: &[u8]) -> Option<Vec<u8>> {
+        base64_url::decode(text).ok()
+    }
+
+    #[inline]
+    fn base_to_symbol(&self, base: u8) -> u8 {
+        BASE_SYMBOLS[base as usize]
+    }
+
+    #[inline]
+    fn symbol_to_base(&self, symbol: u8) -> Option<u8> {
+        match BASE_DECODING_TABLE[symbol as usize] {
+            0xFF => None,
+            base => Some(base),
+        }
+    }
+}
+
+impl BitsEncoding for UrlComponentEncoding {
+    const BITS: u32 = 6;
+    const DECODING_TABLE: &'static [u8; 256] = &URL_COMPONENT_DECODING_TABLE;
+    const ERROR: ShortCryptError = ShortCryptError::DecodeBase64;
+    // Base64 rejects a body whose unused bits are not zero, so this does too
+    const STRICT: bool = true;
+}
+
+/// The encoding used by `encrypt_to_qr_code_alphanumeric` and `decrypt_qr_code_alphanumeric`. The **body** is encoded with Base32 (RFC 4648) without padding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QrCodeAlphanumericEncoding;
+
+impl OutputEncoding for QrCodeAlphanumericEncoding {
+    #[inline]
+    fn alphabet(&self) -> &[u8] {
+        QR_CODE_ALPHANUMERIC_ALPHABET
+    }
+
+    #[inline]
+    fn encode(&self, data: &[u8], output: &mut String) {
+        bits::encode(data, BASE32_ALPHABET, 5, output);
+    }
+
+    #[inline]
+    fn decode(&self, text: &[u8]) -> Option<Vec<u8>> {
+        let text = core::str::from_utf8(text).ok()?;
+
+        base32::decode(
+            base32::Alphabet::RFC4648 {
+                padding: false
+            },
+            text,
+        )
+    }
+
+    #[inline]
+    fn base_to_symbol(&self, base: u8) -> u8 {
+        BASE_SYMBOLS[base as usize]
+    }
+
+    #[inline]
+    fn symbol_to_base(&self, symbol: u8) -> Option<u8> {
+        match BASE_DECODING_TABLE[symbol as usize] {
+            0xFF => None,
+            base => Some(base),
+        }
+    }
+}
+
+impl BitsEncoding for QrCodeAlphanumericEncoding {
+    const BITS: u32 = 5;
+    const DECODING_TABLE: &'static [u8; 256] = &BASE32_DECODING_TABLE;
+    const ERROR: ShortCryptError = ShortCryptError::DecodeBase32;
+    const STRICT: bool = false;
+}
+
+/// The encoding used by `encrypt_to_base32hex` and `decrypt_base32hex`. The **body** is encoded with Base32 with the extended hex alphabet (RFC 4648) without padding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Base32HexEncoding;
+
+impl OutputEncoding for Base32HexEncoding {
+    #[inline]
+    fn alphabet(&self) -> &[u8] {
+        BASE_SYMBOLS
+    }
+
+    #[inline]
+    fn encode(&self, data: &[u8], output: &mut String) {
+        bits::encode(data, BASE_SYMBOLS, 5, output);
+    }
+
+    fn decode(&self, text: &[u8]) -> Option<Vec<u8>> {
+        let mut digits = Vec::with_capacity(text.len());
+
+        for n in text.iter().copied() {
+            match BASE_DECODING_TABLE[n as usize] {
+                0xFF => return None,
+                d => digits.push(d),
+            }
+        }
+
+        bits::decode(&digits, 5)
+    }
+
+    #[inline]
+    fn base_to_symbol(&self, base: u8) -> u8 {
+        BASE_SYMBOLS[base as usize]
+    }
+
+    #[inline]
+    fn symbol_to_base(&self, symbol: u8) -> Option<u8> {
+        match BASE_DECODING_TABLE[symbol as usize] {
+            0xFF => None,
+            base => Some(base),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{ShortCrypt, ShortCryptError};
+
+/// Provide the `ShortCrypt` instance used by `EncryptedId<Self>` and the field helpers in the `serde` module. Different ID types can use different keys.
+pub trait EncryptedIdKey {
+    fn short_crypt() -> &'static ShortCrypt;
+}
+
+/// An integer ID which is formatted as an encrypted URL component (with `Display`) and parsed back from it (with `FromStr`). The key is provided by the `EncryptedIdKey` implementation of `T`, so IDs of different types cannot be mixed up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct EncryptedId<T>(pub T);
+
+impl<T> EncryptedId<T> {
+    #[inline]
+    pub const fn new(id: T) -> EncryptedId<T> {
+        EncryptedId(id)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for EncryptedId<T> {
+    #[inline]
+    fn from(id: T) -> EncryptedId<T> {
+        EncryptedId(id)
+    }
+}
+
+impl<T: Copy + Into<u64> + EncryptedIdKey> Display for EncryptedId<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&T::short_crypt().encrypt_u64_varint_to_url_component(self.0.into()))
+    }
+}
+
+impl<T: TryFrom<u64> + EncryptedIdKey> FromStr for EncryptedId<T> {
+    type Err = ShortCryptError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<EncryptedId<T>, ShortCryptError> {
+        let id = T::short_crypt().decrypt_url_component_to_u64_varint(s)?;
+
+        T::try_from(id).map(EncryptedId).map_err(|_| ShortCryptError::OutOfRange)
+    }
+}
@@ -0,0 +1,115 @@
+use core::fmt::{self, Display, Formatter};
+
+/// Errors returned by `ShortCrypt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShortCryptError {
+    /// The **base** is not in the range of `0` to `31`.
+    InvalidBase,
+    /// The length of a text, a cipher or a plaintext is incorrect.
+    InvalidLength,
+    /// The text contains a character which is not in the alphabet of its format. `index` is the byte index of the character in the text.
+    InvalidCharacter { index: usize },
+    /// The Base64 (or Base64-URL) body cannot be decoded.
+    DecodeBase64,
+    /// The Base32 body cannot be decoded.
+    DecodeBase32,
+    /// The text does not follow its format.
+    InvalidText,
+    /// The checksum or the check digit does not match.
+    InvalidChecksum,
+    /// The human-readable part of a Bech32 text is incorrect.
+    InvalidHrp,
+    /// The plaintext (or a path) is not valid UTF-8.
+    Utf8,
+    /// The plaintext is too long for the format.
+    DataTooLong,
+    /// The output buffer is too small.
+    BufferTooSmall,
+    /// The token has expired.
+    Expired,
+    /// The characters of a custom alphabet are incorrect.
+    InvalidAlphabet,
+    /// The structure of the plaintext (e.g. a varint, fields or a cursor) is incorrect.
+    InvalidPayload,
+    /// A decrypted number is out of the range of its type.
+    OutOfRange,
+    /// The value cannot be serialized.
+    Serialize,
+    /// The value cannot be deserialized.
+    Deserialize,
+    /// The format of the text cannot be recognized.
+    UnknownFormat,
+    /// No key is registered for the key ID of the token.
+    UnknownKeyId,
+    /// The key is empty.
+    EmptyKey,
+    /// The estimated entropy of the key is below the required threshold.
+    WeakKey,
+}
+
+impl ShortCryptError {
+    /// Get the description of this error.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ShortCryptError::InvalidBase => "The base is not correct.",
+            ShortCryptError::InvalidLength => "The length is incorrect.",
+            ShortCryptError::InvalidCharacter {
+                ..
+            } => "The text contains an invalid character.",
+            ShortCryptError::DecodeBase64 => "The Base64 text is incorrect.",
+            ShortCryptError::DecodeBase32 => "The Base32 text is incorrect.",
+            ShortCryptError::InvalidText => "The text is incorrect.",
+            ShortCryptError::InvalidChecksum => "The checksum is incorrect.",
+            ShortCryptError::InvalidHrp => "The human-readable part is incorrect.",
+            ShortCryptError::Utf8 => "The plaintext is not valid UTF-8.",
+            ShortCryptError::DataTooLong => "The data is too long for the format.",
+            ShortCryptError::BufferTooSmall => "The buffer is too small.",
+            ShortCryptError::Expired => "The token has expired.",
+            ShortCryptError::InvalidAlphabet => {
+                "The alphabet must have 32 or 64 distinct, visible ASCII characters."
+            },
+            ShortCryptError::InvalidPayload => "The plaintext is incorrect.",
+            ShortCryptError::OutOfRange => "The number is out of range.",
+            ShortCryptError::Serialize => "The value cannot be serialized.",
+            ShortCryptError::Deserialize => "The value cannot be deserialized.",
+            ShortCryptError::UnknownFormat => "The format of the text cannot be recognized.",
+            ShortCryptError::UnknownKeyId => "No key is registered for the key ID.",
+            ShortCryptError::EmptyKey => "The key is empty.",
+            ShortCryptError::WeakKey => "The key is too weak.",
+        }
+    }
+
+    /// Get the byte index of the offending character in the text, if this error is caused by one.
+    #[inline]
+    pub const fn index(&self) -> Option<usize> {
+        match self {
+            ShortCryptError::InvalidCharacter {
+                index,
+            } => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ShortCryptError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ShortCryptError::InvalidCharacter {
+                index,
+            } => f.write_fmt(format_args!("The character at index {} is invalid.", index)),
+            _ => f.write_str(self.as_str()),
+        }
+    }
+}
+
+impl core::error::Error for ShortCryptError {}
+
+/// Keep the call sites which used to receive `&'static str` errors compiling.
+impl From<ShortCryptError> for &'static str {
+    #[inline]
+    fn from(error: ShortCryptError) -> &'static str {
+        error.as_str()
+    }
+}
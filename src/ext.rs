@@ -0,0 +1,60 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{ShortCrypt, ShortCryptError};
+
+/// Encrypt or decrypt a value directly, e.g. `"articles".encrypt_url_component(&sc)`. It is implemented for all types which implement `AsRef<[u8]>`, such as `str`, `[u8]`, `String` and `Vec<u8>`.
+pub trait ShortCryptExt {
+    /// Same as `ShortCrypt::encrypt_to_url_component`.
+    fn encrypt_url_component(&self, short_crypt: &ShortCrypt) -> String;
+
+    /// Same as `ShortCrypt::decrypt_url_component`, with this value as the text.
+    fn decrypt_url_component(&self, short_crypt: &ShortCrypt) -> Result<Vec<u8>, ShortCryptError>;
+
+    /// Same as `ShortCrypt::encrypt_to_qr_code_alphanumeric`.
+    fn encrypt_qr_code_alphanumeric(&self, short_crypt: &ShortCrypt) -> String;
+
+    /// Same as `ShortCrypt::decrypt_qr_code_alphanumeric`, with this value as the text.
+    fn decrypt_qr_code_alphanumeric(
+        &self,
+        short_crypt: &ShortCrypt,
+    ) -> Result<Vec<u8>, ShortCryptError>;
+}
+
+impl<T: ?Sized + AsRef<[u8]>> ShortCryptExt for T {
+    #[inline]
+    fn encrypt_url_component(&self, short_crypt: &ShortCrypt) -> String {
+        short_crypt.encrypt_to_url_component(self)
+    }
+
+    #[inline]
+    fn decrypt_url_component(&self, short_crypt: &ShortCrypt) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = Vec::new();
+
+        let (base, m, sum) =
+            short_crypt.decode_url_component_into(self.as_ref(), &mut plaintext)?;
+
+        short_crypt.decrypt_folded(&mut plaintext, base, m, sum)?;
+
+        Ok(plaintext)
+    }
+
+    #[inline]
+    fn encrypt_qr_code_alphanumeric(&self, short_crypt: &ShortCrypt) -> String {
+        short_crypt.encrypt_to_qr_code_alphanumeric(self)
+    }
+
+    #[inline]
+    fn decrypt_qr_code_alphanumeric(
+        &self,
+        short_crypt: &ShortCrypt,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = Vec::new();
+
+        let (base, m, sum) =
+            short_crypt.decode_qr_code_alphanumeric_into(self.as_ref(), &mut plaintext)?;
+
+        short_crypt.decrypt_folded(&mut plaintext, base, m, sum)?;
+
+        Ok(plaintext)
+    }
+}
@@ -0,0 +1,44 @@
+//! Length-prefixed fields used by the multi-field tokens. Every field is prefixed with its length as a LEB128 integer.
+
+use alloc::vec::Vec;
+
+use crate::varint;
+
+/// Pack fields into bytes.
+pub(crate) fn pack<F: AsRef<[u8]>>(fields: &[F]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(fields.iter().map(|f| f.as_ref().len() + 1).sum());
+
+    for field in fields {
+        let field = field.as_ref();
+
+        let (len, len_len) = varint::encode(field.len() as u64);
+
+        bytes.extend_from_slice(&len[..len_len]);
+        bytes.extend_from_slice(field);
+    }
+
+    bytes
+}
+
+/// Unpack bytes created by `pack`. Returns `None` if a length prefix is invalid or exceeds the remaining bytes.
+pub(crate) fn unpack(mut bytes: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let mut fields = Vec::new();
+
+    while !bytes.is_empty() {
+        let (len, len_len) = varint::decode_prefix(bytes)?;
+
+        bytes = &bytes[len_len..];
+
+        if len > bytes.len() as u64 {
+            return None;
+        }
+
+        let (field, rest) = bytes.split_at(len as usize);
+
+        fields.push(field.to_vec());
+
+        bytes = rest;
+    }
+
+    Some(fields)
+}
@@ -0,0 +1,16 @@
+/// Built-in text formats which can be recognized by `ShortCrypt::decrypt_any`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Created by `encrypt_to_uuid`.
+    Uuid,
+    /// Created by `encrypt_to_qr_code_numeric`.
+    QrCodeNumeric,
+    /// Created by `encrypt_to_hex`.
+    Hex,
+    /// Created by `encrypt_to_qr_code_alphanumeric`.
+    QrCodeAlphanumeric,
+    /// Created by `encrypt_to_url_component`.
+    UrlComponent,
+    /// Created by `encrypt_to_emoji`.
+    Emoji,
+}
@@ -0,0 +1,84 @@
+//! A process-wide `ShortCrypt` instance, for applications which use one key everywhere. It is initialized once by `init` (or `set`), usually at startup, and then used by the free functions of this module.
+//!
+//! ```rust
+//! short_crypt::global::init("magickey").unwrap();
+//!
+//! assert_eq!(
+//!     "2E87Wx52-Tvo",
+//!     short_crypt::global::encrypt_url_component("articles")
+//! );
+//! assert_eq!(
+//!     b"articles".to_vec(),
+//!     short_crypt::global::decrypt_url_component("2E87Wx52-Tvo").unwrap()
+//! );
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use std::sync::OnceLock;
+
+use crate::{EncryptedIdKey, ShortCrypt, ShortCryptError};
+
+static GLOBAL: OnceLock<ShortCrypt> = OnceLock::new();
+
+/// Initialize the global instance with `key`. If it has been initialized, the instance created from `key` is returned as an error.
+#[inline]
+pub fn init<K: AsRef<[u8]>>(key: K) -> Result<(), ShortCrypt> {
+    set(ShortCrypt::new_bytes(key))
+}
+
+/// Initialize the global instance with an existing `ShortCrypt` instance. If it has been initialized, `short_crypt` is returned as an error.
+#[inline]
+pub fn set(short_crypt: ShortCrypt) -> Result<(), ShortCrypt> {
+    GLOBAL.set(short_crypt)
+}
+
+/// Get the global instance, or `None` if it has not been initialized.
+#[inline]
+pub fn get() -> Option<&'static ShortCrypt> {
+    GLOBAL.get()
+}
+
+/// Get the global instance. It panics if the global instance has not been initialized.
+#[inline]
+pub fn short_crypt() -> &'static ShortCrypt {
+    GLOBAL.get().expect(
+        "the global ShortCrypt instance has not been initialized by `short_crypt::global::init`",
+    )
+}
+
+/// Encrypt `data` into a URL component with the global instance, like `ShortCrypt::encrypt_to_url_component`. It panics if the global instance has not been initialized.
+#[inline]
+pub fn encrypt_url_component<T: ?Sized + AsRef<[u8]>>(data: &T) -> String {
+    short_crypt().encrypt_to_url_component(data)
+}
+
+/// Decrypt a URL component with the global instance, like `ShortCrypt::decrypt_url_component`. It panics if the global instance has not been initialized.
+#[inline]
+pub fn decrypt_url_component<S: AsRef<str>>(url_component: S) -> Result<Vec<u8>, ShortCryptError> {
+    short_crypt().decrypt_url_component(url_component)
+}
+
+/// Encrypt `data` into a QR code alphanumeric text with the global instance, like `ShortCrypt::encrypt_to_qr_code_alphanumeric`. It panics if the global instance has not been initialized.
+#[inline]
+pub fn encrypt_qr_code_alphanumeric<T: ?Sized + AsRef<[u8]>>(data: &T) -> String {
+    short_crypt().encrypt_to_qr_code_alphanumeric(data)
+}
+
+/// Decrypt a QR code alphanumeric text with the global instance, like `ShortCrypt::decrypt_qr_code_alphanumeric`. It panics if the global instance has not been initialized.
+#[inline]
+pub fn decrypt_qr_code_alphanumeric<S: AsRef<str>>(
+    qr_code_alphanumeric: S,
+) -> Result<Vec<u8>, ShortCryptError> {
+    short_crypt().decrypt_qr_code_alphanumeric(qr_code_alphanumeric)
+}
+
+/// An `EncryptedIdKey` which provides the global instance, so the field helpers in the `serde` module and `ShortCryptString<Global>` can use it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Global;
+
+impl EncryptedIdKey for Global {
+    #[inline]
+    fn short_crypt() -> &'static ShortCrypt {
+        short_crypt()
+    }
+}
@@ -0,0 +1,53 @@
+//! A buffer for the **body** of a cipher which is kept on the stack when it is short, so encrypting a typical token into a text does not allocate for the intermediate **body**.
+
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+/// Bodies up to this length are kept inline. Most tokens are shorter than this.
+pub(crate) const INLINE_CAPACITY: usize = 32;
+
+pub(crate) enum InlineBuffer {
+    Inline([u8; INLINE_CAPACITY], usize),
+    Heap(Vec<u8>),
+}
+
+impl InlineBuffer {
+    /// Create a buffer of `len` zeros, which is on the heap only if `len` is larger than `INLINE_CAPACITY`.
+    #[inline]
+    pub(crate) fn zeroed(len: usize) -> InlineBuffer {
+        if len <= INLINE_CAPACITY {
+            InlineBuffer::Inline([0; INLINE_CAPACITY], len)
+        } else {
+            InlineBuffer::Heap(vec![0; len])
+        }
+    }
+}
+
+impl Deref for InlineBuffer {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        match self {
+            InlineBuffer::Inline(buffer, len) => &buffer[..*len],
+            InlineBuffer::Heap(buffer) => buffer,
+        }
+    }
+}
+
+impl DerefMut for InlineBuffer {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            InlineBuffer::Inline(buffer, len) => &mut buffer[..*len],
+            InlineBuffer::Heap(buffer) => buffer,
+        }
+    }
+}
+
+impl AsRef<[u8]> for InlineBuffer {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{ShortCrypt, ShortCryptError};
+
+/// Extension methods which encrypt or decrypt every item of an iterator lazily. It is implemented for all iterators.
+pub trait ShortCryptIteratorExt: Iterator + Sized {
+    /// Encrypt every item into a URL component, like `ShortCrypt::encrypt_to_url_component`.
+    #[inline]
+    fn short_encrypt_url_components(
+        self,
+        short_crypt: &ShortCrypt,
+    ) -> impl Iterator<Item = String>
+    where
+        Self::Item: AsRef<[u8]>, {
+        self.map(move |item| short_crypt.encrypt_to_url_component(&item))
+    }
+
+    /// Decrypt every URL component item, like `ShortCrypt::decrypt_url_component`.
+    #[inline]
+    fn short_decrypt_url_components(
+        self,
+        short_crypt: &ShortCrypt,
+    ) -> impl Iterator<Item = Result<Vec<u8>, ShortCryptError>>
+    where
+        Self::Item: AsRef<str>, {
+        self.map(move |item| short_crypt.decrypt_url_component(item))
+    }
+
+    /// Encrypt every item into a QR code alphanumeric text, like `ShortCrypt::encrypt_to_qr_code_alphanumeric`.
+    #[inline]
+    fn short_encrypt_qr_code_alphanumerics(
+        self,
+        short_crypt: &ShortCrypt,
+    ) -> impl Iterator<Item = String>
+    where
+        Self::Item: AsRef<[u8]>, {
+        self.map(move |item| short_crypt.encrypt_to_qr_code_alphanumeric(&item))
+    }
+
+    /// Decrypt every QR code alphanumeric item, like `ShortCrypt::decrypt_qr_code_alphanumeric`.
+    #[inline]
+    fn short_decrypt_qr_code_alphanumerics(
+        self,
+        short_crypt: &ShortCrypt,
+    ) -> impl Iterator<Item = Result<Vec<u8>, ShortCryptError>>
+    where
+        Self::Item: AsRef<str>, {
+        self.map(move |item| short_crypt.decrypt_qr_code_alphanumeric(item))
+    }
+}
+
+impl<I: Iterator> ShortCryptIteratorExt for I {}
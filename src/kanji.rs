@@ -0,0 +1,106 @@
+//! Shift JIS kanji conversion used by the QR code kanji text format. Each character carries 12 bits. The characters are taken from the fully assigned rows of the JIS X 0208 kanji, so every QR code reader can map them to Unicode.
+
+use alloc::vec::Vec;
+
+/// The first row of the level-1 kanji in JIS X 0208.
+const FIRST_ROW: u16 = 16;
+/// The last row of the level-1 kanji, which is not fully assigned.
+const SKIPPED_ROW: u16 = 47;
+const CELLS_PER_ROW: u16 = 94;
+
+/// Convert a 12-bit value to a Shift JIS kanji character.
+pub(crate) fn to_shift_jis(value: u16) -> [u8; 2] {
+    debug_assert!(value < 4096);
+
+    let mut row = FIRST_ROW + value / CELLS_PER_ROW;
+    let cell = value % CELLS_PER_ROW + 1;
+
+    if row >= SKIPPED_ROW {
+        row += 1;
+    }
+
+    // rows 16 to 61 are all in the lead byte range 0x88 to 0x9E
+    let lead = (row.div_ceil(2) + 0x80) as u8;
+
+    let trail = if row % 2 == 0 {
+        cell + 0x9E
+    } else if cell <= 63 {
+        cell + 0x3F
+    } else {
+        cell + 0x40
+    };
+
+    [lead, trail as u8]
+}
+
+/// Convert a Shift JIS kanji character created by `to_shift_jis` back to its 12-bit value.
+pub(crate) fn from_shift_jis(lead: u8, trail: u8) -> Option<u16> {
+    if !(0x88..=0x9E).contains(&lead) {
+        return None;
+    }
+
+    let odd_row = u16::from(lead - 0x80) * 2 - 1;
+
+    let (row, cell) = match trail {
+        0x40..=0x7E => (odd_row, u16::from(trail) - 0x3F),
+        0x80..=0x9E => (odd_row, u16::from(trail) - 0x40),
+        0x9F..=0xFC => (odd_row + 1, u16::from(trail) - 0x9E),
+        _ => return None,
+    };
+
+    let row_index = match row {
+        FIRST_ROW..=46 => row - FIRST_ROW,
+        48..=61 => row - FIRST_ROW - 1,
+        _ => return None,
+    };
+
+    let value = row_index * CELLS_PER_ROW + cell - 1;
+
+    if value < 4096 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Encode `data` (MSB first, 12 bits per character, zero-padded) and push the Shift JIS bytes to `output`.
+pub(crate) fn encode(data: &[u8], output: &mut Vec<u8>) {
+    output.reserve((data.len() * 8).div_ceil(12) * 2);
+
+    for chunk in data.chunks(3) {
+        let mut group = [0u8; 3];
+
+        group[..chunk.len()].copy_from_slice(chunk);
+
+        let high = (u16::from(group[0]) << 4) | u16::from(group[1] >> 4);
+        let low = (u16::from(group[1] & 0x0F) << 8) | u16::from(group[2]);
+
+        output.extend_from_slice(&to_shift_jis(high));
+
+        if chunk.len() > 1 {
+            output.extend_from_slice(&to_shift_jis(low));
+        }
+    }
+}
+
+/// Decode 12-bit values back to bytes. Every pair of values becomes three bytes and a single value at the end becomes one byte. Returns `None` if the low 4 bits of such a single value, which `encode` pads with zeros, are not zero.
+pub(crate) fn decode(values: &[u16]) -> Option<Vec<u8>> {
+    let mut result = Vec::with_capacity(values.len() * 3 / 2);
+
+    for pair in values.chunks(2) {
+        let high = pair[0];
+
+        result.push((high >> 4) as u8);
+
+        match pair.get(1).copied() {
+            Some(low) => {
+                result.push((((high & 0x0F) << 4) | (low >> 8)) as u8);
+                result.push(low as u8);
+            },
+            None if high & 0x0F != 0 => return None,
+            None => (),
+        }
+    }
+
+    Some(result)
+}
@@ -0,0 +1,98 @@
+//! Loading keys from files, as servers usually keep their secrets outside the source code.
+
+use alloc::vec::Vec;
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Errors returned by `ShortCrypt::from_key_file` and `ShortCrypt::from_key_file_checked`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KeyFileError {
+    /// The file cannot be opened or read.
+    Io(io::Error),
+    /// The file contains no key after the trailing newlines are trimmed.
+    Empty,
+    /// The file can be accessed by the group or other users. `mode` is the permission bits of the file.
+    InsecurePermissions { mode: u32 },
+}
+
+impl Display for KeyFileError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            KeyFileError::Io(error) => Display::fmt(error, f),
+            KeyFileError::Empty => f.write_str("The key file is empty."),
+            KeyFileError::InsecurePermissions {
+                mode,
+            } => f.write_fmt(format_args!(
+                "The key file can be accessed by other users (mode {:o}).",
+                mode
+            )),
+        }
+    }
+}
+
+impl std::error::Error for KeyFileError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KeyFileError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for KeyFileError {
+    #[inline]
+    fn from(error: io::Error) -> KeyFileError {
+        KeyFileError::Io(error)
+    }
+}
+
+/// Read a key from `path` and trim its trailing newlines (`\n` or `\r\n`). If `check_permissions` is `true`, the file must not be accessible by the group or other users on Unix.
+pub(crate) fn read(path: &Path, check_permissions: bool) -> Result<Vec<u8>, KeyFileError> {
+    let mut file = File::open(path)?;
+
+    if check_permissions {
+        check(&file)?;
+    }
+
+    let mut key = Vec::new();
+
+    file.read_to_end(&mut key)?;
+
+    while let Some(b'\n' | b'\r') = key.last() {
+        key.pop();
+    }
+
+    if key.is_empty() {
+        return Err(KeyFileError::Empty);
+    }
+
+    Ok(key)
+}
+
+#[cfg(unix)]
+fn check(file: &File) -> Result<(), KeyFileError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = file.metadata()?.permissions().mode() & 0o777;
+
+    if mode & 0o077 != 0 {
+        return Err(KeyFileError::InsecurePermissions {
+            mode,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+#[inline]
+fn check(_file: &File) -> Result<(), KeyFileError> {
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use alloc::vec::Vec;
+
+use crate::{ShortCrypt, ShortCryptError, BASE32_DECODING_TABLE, URL_COMPONENT_DECODING_TABLE};
+
+/// The largest key ID, so that it can be represented by one character of both the URL component and the QR code alphanumeric alphabets.
+pub(crate) const MAX_KEY_ID: u8 = 31;
+
+/// Decrypt texts created by `ShortCrypt::encrypt_to_url_component_with_key_id` or `ShortCrypt::encrypt_to_qr_code_alphanumeric_with_key_id`. The first character of a text is the key ID, which selects the key directly, unlike a `KeyRing` which tries every key. Key IDs are in the range of `0` to `31`.
+#[derive(Debug, Clone, Default)]
+pub struct KeyedDecoder {
+    keys: [Option<ShortCrypt>; MAX_KEY_ID as usize + 1],
+}
+
+impl KeyedDecoder {
+    #[inline]
+    pub fn new() -> KeyedDecoder {
+        KeyedDecoder::default()
+    }
+
+    /// Register `key` for `key_id`. Returns the old key if `key_id` has been registered.
+    #[inline]
+    pub fn insert(
+        &mut self,
+        key_id: u8,
+        key: ShortCrypt,
+    ) -> Result<Option<ShortCrypt>, ShortCryptError> {
+        let slot = self.keys.get_mut(key_id as usize).ok_or(ShortCryptError::OutOfRange)?;
+
+        Ok(slot.replace(key))
+    }
+
+    #[inline]
+    pub fn remove(&mut self, key_id: u8) -> Option<ShortCrypt> {
+        self.keys.get_mut(key_id as usize)?.take()
+    }
+
+    #[inline]
+    pub fn get(&self, key_id: u8) -> Option<&ShortCrypt> {
+        self.keys.get(key_id as usize)?.as_ref()
+    }
+
+    /// Decrypt a text created by `ShortCrypt::encrypt_to_url_component_with_key_id`. Returns the key ID and the plaintext.
+    #[inline]
+    pub fn decrypt_url_component<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        self.decrypt(url_component.as_ref(), &URL_COMPONENT_DECODING_TABLE, |key, text| {
+            key.decrypt_url_component(text)
+        })
+    }
+
+    /// Decrypt a text created by `ShortCrypt::encrypt_to_qr_code_alphanumeric_with_key_id`. Returns the key ID and the plaintext.
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        self.decrypt(qr_code_alphanumeric.as_ref(), &BASE32_DECODING_TABLE, |key, text| {
+            key.decrypt_qr_code_alphanumeric(text)
+        })
+    }
+
+    fn decrypt<F: FnOnce(&ShortCrypt, &str) -> Result<Vec<u8>, ShortCryptError>>(
+        &self,
+        text: &str,
+        decoding_table: &[u8; 256],
+        decrypt: F,
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        let first = *text.as_bytes().first().ok_or(ShortCryptError::InvalidLength)?;
+
+        let key_id = decoding_table[first as usize];
+
+        if key_id > MAX_KEY_ID {
+            return Err(ShortCryptError::InvalidCharacter {
+                index: 0
+            });
+        }
+
+        let key = self.get(key_id).ok_or(ShortCryptError::UnknownKeyId)?;
+
+        // the key ID is a single ASCII character
+        let plaintext = decrypt(key, &text[1..]).map_err(|error| match error {
+            ShortCryptError::InvalidCharacter {
+                index,
+            } => ShortCryptError::InvalidCharacter {
+                index: index + 1
+            },
+            _ => error,
+        })?;
+
+        Ok((key_id, plaintext))
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{compute_base, Cipher, ShortCrypt, ShortCryptError};
+
+/// An ordered list of keys for key rotation. Data is always encrypted with the **primary** key (the first one), and a text is decrypted by trying every key in order, so the tokens issued with older keys keep working until those keys are removed.
+///
+/// A cipher carries no authentication tag. A key is accepted when the **base** recomputed from the decrypted plaintext matches the **base** of the cipher, which is only a 5-bit check, so a wrong key is accepted with a probability of about 1/32. Keep the list short and ordered from the newest key to the oldest one.
+#[derive(Debug, Clone)]
+pub struct KeyRing {
+    keys: Vec<ShortCrypt>,
+}
+
+impl KeyRing {
+    /// Create a key ring with its **primary** key.
+    #[inline]
+    pub fn new(primary: ShortCrypt) -> KeyRing {
+        KeyRing {
+            keys: vec![primary]
+        }
+    }
+
+    /// Append an older key, which is only used for decryption.
+    #[inline]
+    pub fn push(&mut self, key: ShortCrypt) {
+        self.keys.push(key);
+    }
+
+    /// Make `key` the new **primary** key. The previous keys are kept after it for decryption.
+    #[inline]
+    pub fn rotate(&mut self, key: ShortCrypt) {
+        self.keys.insert(0, key);
+    }
+
+    /// Remove the oldest key, unless it is the **primary** key.
+    #[inline]
+    pub fn retire_oldest(&mut self) -> Option<ShortCrypt> {
+        if self.keys.len() > 1 {
+            self.keys.pop()
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn primary(&self) -> &ShortCrypt {
+        &self.keys[0]
+    }
+
+    /// The keys, from the **primary** key to the oldest one.
+    #[inline]
+    pub fn keys(&self) -> &[ShortCrypt] {
+        &self.keys
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Always `false`, because a key ring has at least its **primary** key.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl KeyRing {
+    /// Encrypt `data` with the **primary** key.
+    #[inline]
+    pub fn encrypt<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> Cipher {
+        self.primary().encrypt(data)
+    }
+
+    /// Encrypt `data` into a URL component with the **primary** key.
+    #[inline]
+    pub fn encrypt_to_url_component<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.primary().encrypt_to_url_component(data)
+    }
+
+    /// Encrypt `data` into a QR code alphanumeric text with the **primary** key.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.primary().encrypt_to_qr_code_alphanumeric(data)
+    }
+
+    /// Decrypt a cipher by trying every key in order. Returns the index of the accepted key and the plaintext.
+    pub fn decrypt(&self, data: &Cipher) -> Result<(usize, Vec<u8>), ShortCryptError> {
+        self.find(|_| Ok((data.base(), data.body().to_vec())))
+    }
+
+    /// Decrypt a text created by `encrypt_to_url_component` of any key in this ring. Returns the index of the accepted key and the plaintext. The position of the **base** depends on the key, so the text is decoded again for every key.
+    #[inline]
+    pub fn decrypt_url_component<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<(usize, Vec<u8>), ShortCryptError> {
+        let text = url_component.as_ref().as_bytes();
+
+        self.find(|key| key.decode_url_component(text))
+    }
+
+    /// Decrypt a text created by `encrypt_to_qr_code_alphanumeric` of any key in this ring. Returns the index of the accepted key and the plaintext. The position of the **base** depends on the key, so the text is decoded again for every key.
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<(usize, Vec<u8>), ShortCryptError> {
+        let text = qr_code_alphanumeric.as_ref().as_bytes();
+
+        self.find(|key| key.decode_qr_code_alphanumeric(text))
+    }
+
+    /// Decrypt the cipher produced by `decode` with each key, and return the first plaintext whose **base** matches.
+    fn find<F: FnMut(&ShortCrypt) -> Result<(u8, Vec<u8>), ShortCryptError>>(
+        &self,
+        mut decode: F,
+    ) -> Result<(usize, Vec<u8>), ShortCryptError> {
+        let mut error = ShortCryptError::InvalidChecksum;
+
+        for (index, key) in self.keys.iter().enumerate() {
+            let (base, body) = match decode(key) {
+                Ok(cipher) => cipher,
+                Err(err) => {
+                    error = err;
+
+                    continue;
+                },
+            };
+
+            let plaintext = key.decrypt_vec(base, body)?;
+
+            if compute_base(&plaintext) == base {
+                return Ok((index, plaintext));
+            }
+
+            error = ShortCryptError::InvalidChecksum;
+        }
+
+        Err(error)
+    }
+}
+
+impl From<ShortCrypt> for KeyRing {
+    #[inline]
+    fn from(primary: ShortCrypt) -> KeyRing {
+        KeyRing::new(primary)
+    }
+}
@@ -0,0 +1,262 @@
+//! The per-byte loops of encryption and decryption: XORing a **body** with the repeating key, and folding it into the XOR and the sum of its bytes. When encrypting into a new buffer, the copy of the plaintext, the XOR and the folding are fused into one pass.
+//!
+//! With the `simd` feature, 16 bytes are processed per iteration with SSE2 on x86/x86_64 or with NEON on AArch64. Other targets, and builds without the feature, only use the scalar loops, which also handle the bytes left over by the vector loops.
+
+/// XOR the `i`-th byte of `data` with `key[i % 8]`.
+#[inline]
+pub(crate) fn xor_key(data: &mut [u8], key: [u8; 8]) {
+    let rest = simd::xor_key(data, key);
+
+    // the vector loops only process multiples of 16 bytes, so the rest still starts at `key[0]`
+    for (i, v) in rest.iter_mut().enumerate() {
+        *v ^= key[i % 8];
+    }
+}
+
+/// Same as copying `src` into `dst` and calling `xor_key` and `fold` on `dst`, but in one pass. `dst` must have the same length as `src`.
+#[inline]
+pub(crate) fn xor_key_fold_into(src: &[u8], dst: &mut [u8], key: [u8; 8]) -> (u8, u64) {
+    let (mut m, mut sum, done) = simd::xor_key_fold_into(src, dst, key);
+
+    // the vector loops only process multiples of 16 bytes, so the rest still starts at `key[0]`
+    for (i, (v, n)) in dst[done..].iter_mut().zip(src[done..].iter()).enumerate() {
+        *v = *n ^ key[i % 8];
+
+        m ^= *v;
+        sum = sum.wrapping_add(u64::from(*v));
+    }
+
+    (m, sum)
+}
+
+/// Get the XOR and the wrapping sum of all the bytes of `data`.
+#[inline]
+pub(crate) fn fold(data: &[u8]) -> (u8, u64) {
+    let (mut m, mut sum, rest) = simd::fold(data);
+
+    for v in rest.iter().copied() {
+        m ^= v;
+        sum = sum.wrapping_add(u64::from(v));
+    }
+
+    (m, sum)
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod simd {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    pub(super) fn xor_key(data: &mut [u8], key: [u8; 8]) -> &mut [u8] {
+        let mut key_16 = [0u8; 16];
+
+        key_16[..8].copy_from_slice(&key);
+        key_16[8..].copy_from_slice(&key);
+
+        let mut chunks = data.chunks_exact_mut(16);
+
+        // SAFETY: SSE2 is enabled at compile time, and every unaligned load and store covers exactly 16 bytes of `key_16` or of a chunk.
+        unsafe {
+            let key = _mm_loadu_si128(key_16.as_ptr().cast());
+
+            for chunk in &mut chunks {
+                let p = chunk.as_mut_ptr().cast::<__m128i>();
+
+                _mm_storeu_si128(p, _mm_xor_si128(_mm_loadu_si128(p), key));
+            }
+        }
+
+        chunks.into_remainder()
+    }
+
+    pub(super) fn fold(data: &[u8]) -> (u8, u64, &[u8]) {
+        let chunks = data.chunks_exact(16);
+        let rest = chunks.remainder();
+
+        let mut m = [0u8; 16];
+        let mut sum = [0u64; 2];
+
+        // SAFETY: SSE2 is enabled at compile time, and every unaligned load and store covers exactly 16 bytes of a chunk, `m` or `sum`.
+        unsafe {
+            let zero = _mm_setzero_si128();
+
+            let mut m_v = zero;
+            let mut sum_v = zero;
+
+            for chunk in chunks {
+                let v = _mm_loadu_si128(chunk.as_ptr().cast());
+
+                m_v = _mm_xor_si128(m_v, v);
+                // `_mm_sad_epu8` against zero adds up each half of the vector into a 64-bit lane
+                sum_v = _mm_add_epi64(sum_v, _mm_sad_epu8(v, zero));
+            }
+
+            _mm_storeu_si128(m.as_mut_ptr().cast(), m_v);
+            _mm_storeu_si128(sum.as_mut_ptr().cast(), sum_v);
+        }
+
+        (m.iter().fold(0, |a, b| a ^ b), sum[0].wrapping_add(sum[1]), rest)
+    }
+
+    pub(super) fn xor_key_fold_into(src: &[u8], dst: &mut [u8], key: [u8; 8]) -> (u8, u64, usize) {
+        let mut key_16 = [0u8; 16];
+
+        key_16[..8].copy_from_slice(&key);
+        key_16[8..].copy_from_slice(&key);
+
+        let mut done = 0;
+
+        let mut m = [0u8; 16];
+        let mut sum = [0u64; 2];
+
+        // SAFETY: SSE2 is enabled at compile time, and every unaligned load and store covers exactly 16 bytes of `key_16`, a chunk, `m` or `sum`.
+        unsafe {
+            let key = _mm_loadu_si128(key_16.as_ptr().cast());
+            let zero = _mm_setzero_si128();
+
+            let mut m_v = zero;
+            let mut sum_v = zero;
+
+            for (d, s) in dst.chunks_exact_mut(16).zip(src.chunks_exact(16)) {
+                let v = _mm_xor_si128(_mm_loadu_si128(s.as_ptr().cast()), key);
+
+                _mm_storeu_si128(d.as_mut_ptr().cast(), v);
+
+                m_v = _mm_xor_si128(m_v, v);
+                sum_v = _mm_add_epi64(sum_v, _mm_sad_epu8(v, zero));
+
+                done += 16;
+            }
+
+            _mm_storeu_si128(m.as_mut_ptr().cast(), m_v);
+            _mm_storeu_si128(sum.as_mut_ptr().cast(), sum_v);
+        }
+
+        (m.iter().fold(0, |a, b| a ^ b), sum[0].wrapping_add(sum[1]), done)
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+mod simd {
+    use core::arch::aarch64::*;
+
+    pub(super) fn xor_key(data: &mut [u8], key: [u8; 8]) -> &mut [u8] {
+        let mut key_16 = [0u8; 16];
+
+        key_16[..8].copy_from_slice(&key);
+        key_16[8..].copy_from_slice(&key);
+
+        let mut chunks = data.chunks_exact_mut(16);
+
+        // SAFETY: NEON is enabled at compile time, and every load and store covers exactly 16 bytes of `key_16` or of a chunk.
+        unsafe {
+            let key = vld1q_u8(key_16.as_ptr());
+
+            for chunk in &mut chunks {
+                let p = chunk.as_mut_ptr();
+
+                vst1q_u8(p, veorq_u8(vld1q_u8(p), key));
+            }
+        }
+
+        chunks.into_remainder()
+    }
+
+    pub(super) fn fold(data: &[u8]) -> (u8, u64, &[u8]) {
+        let chunks = data.chunks_exact(16);
+        let rest = chunks.remainder();
+
+        let mut m = [0u8; 16];
+        let mut sum = [0u64; 2];
+
+        // SAFETY: NEON is enabled at compile time, and every load and store covers exactly 16 bytes of a chunk, `m` or `sum`.
+        unsafe {
+            let mut m_v = vdupq_n_u8(0);
+            let mut sum_v = vdupq_n_u64(0);
+
+            for chunk in chunks {
+                let v = vld1q_u8(chunk.as_ptr());
+
+                m_v = veorq_u8(m_v, v);
+                // widen pairwise (8 -> 16 -> 32 bits) and accumulate into the two 64-bit lanes
+                sum_v = vpadalq_u32(sum_v, vpaddlq_u16(vpaddlq_u8(v)));
+            }
+
+            vst1q_u8(m.as_mut_ptr(), m_v);
+            vst1q_u64(sum.as_mut_ptr(), sum_v);
+        }
+
+        (m.iter().fold(0, |a, b| a ^ b), sum[0].wrapping_add(sum[1]), rest)
+    }
+
+    pub(super) fn xor_key_fold_into(src: &[u8], dst: &mut [u8], key: [u8; 8]) -> (u8, u64, usize) {
+        let mut key_16 = [0u8; 16];
+
+        key_16[..8].copy_from_slice(&key);
+        key_16[8..].copy_from_slice(&key);
+
+        let mut done = 0;
+
+        let mut m = [0u8; 16];
+        let mut sum = [0u64; 2];
+
+        // SAFETY: NEON is enabled at compile time, and every load and store covers exactly 16 bytes of `key_16`, a chunk, `m` or `sum`.
+        unsafe {
+            let key = vld1q_u8(key_16.as_ptr());
+
+            let mut m_v = vdupq_n_u8(0);
+            let mut sum_v = vdupq_n_u64(0);
+
+            for (d, s) in dst.chunks_exact_mut(16).zip(src.chunks_exact(16)) {
+                let v = veorq_u8(vld1q_u8(s.as_ptr()), key);
+
+                vst1q_u8(d.as_mut_ptr(), v);
+
+                m_v = veorq_u8(m_v, v);
+                sum_v = vpadalq_u32(sum_v, vpaddlq_u16(vpaddlq_u8(v)));
+
+                done += 16;
+            }
+
+            vst1q_u8(m.as_mut_ptr(), m_v);
+            vst1q_u64(sum.as_mut_ptr(), sum_v);
+        }
+
+        (m.iter().fold(0, |a, b| a ^ b), sum[0].wrapping_add(sum[1]), done)
+    }
+}
+
+#[cfg(not(any(
+    all(
+        feature = "simd",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(feature = "simd", target_arch = "aarch64", target_feature = "neon")
+)))]
+mod simd {
+    #[inline]
+    pub(super) fn xor_key(data: &mut [u8], _key: [u8; 8]) -> &mut [u8] {
+        data
+    }
+
+    #[inline]
+    pub(super) fn fold(data: &[u8]) -> (u8, u64, &[u8]) {
+        (0, 0, data)
+    }
+
+    #[inline]
+    pub(super) fn xor_key_fold_into(
+        _src: &[u8],
+        _dst: &mut [u8],
+        _key: [u8; 8],
+    ) -> (u8, u64, usize) {
+        (0, 0, 0)
+    }
+}
@@ -0,0 +1,138 @@
+//! The chunked format of `ShortCrypt::encrypt_large` and `ShortCrypt::decrypt_large`.
+//!
+//! The plaintext is split into frames of `ShortCrypt::LARGE_FRAME_LEN` bytes, and every frame is encrypted on its own like `ShortCrypt::encrypt`. The output is the frames one after another, each written as its **base** (one byte) followed by its **body**.
+//!
+//! The last frame is shorter than `ShortCrypt::LARGE_FRAME_LEN` (it is empty if the length of the plaintext is a multiple of it, including an empty plaintext). The highest bit of its **base** byte is set, and the length of its **body** follows as a big-endian `u16`, so a stream which is cut anywhere, or followed by other bytes, can be detected.
+
+use std::io::{self, ErrorKind, Read, Write};
+
+use crate::{ShortCrypt, ShortCryptError};
+
+// the length of the last frame is always less than `FRAME_LEN`, so it fits in a `u16`
+const FRAME_LEN: usize = ShortCrypt::LARGE_FRAME_LEN;
+
+/// Set in the **base** byte of the last frame.
+const FINAL_FRAME: u8 = 0x80;
+
+#[inline]
+fn invalid_data(error: ShortCryptError) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+/// Read until `buffer` is full or the end of `reader` is reached. Returns the number of bytes read.
+fn read_frame<R: ?Sized + Read>(reader: &mut R, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut len = 0;
+
+    while len < buffer.len() {
+        match reader.read(&mut buffer[len..]) {
+            Ok(0) => break,
+            Ok(c) => len += c,
+            Err(error) if error.kind() == ErrorKind::Interrupted => (),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(len)
+}
+
+/// Fill `buffer` from `reader`. A stream which ends earlier is incorrect.
+#[inline]
+fn read_exact<R: ?Sized + Read>(reader: &mut R, buffer: &mut [u8]) -> io::Result<()> {
+    if read_frame(reader, buffer)? < buffer.len() {
+        return Err(invalid_data(ShortCryptError::InvalidLength));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn encrypt<R: ?Sized + Read, W: ?Sized + Write>(
+    short_crypt: &ShortCrypt,
+    reader: &mut R,
+    writer: &mut W,
+) -> io::Result<u64> {
+    let mut frame = vec![0; FRAME_LEN];
+
+    let mut total = 0;
+
+    loop {
+        let len = read_frame(reader, &mut frame)?;
+
+        let body = &mut frame[..len];
+
+        let base = short_crypt.encrypt_in_place(body);
+
+        let is_final = len < FRAME_LEN;
+
+        if is_final {
+            let [a, b] = (len as u16).to_be_bytes();
+
+            writer.write_all(&[base | FINAL_FRAME, a, b])?;
+
+            total += 3;
+        } else {
+            writer.write_all(&[base])?;
+
+            total += 1;
+        }
+
+        writer.write_all(body)?;
+
+        total += len as u64;
+
+        if is_final {
+            break;
+        }
+    }
+
+    Ok(total)
+}
+
+pub(crate) fn decrypt<R: ?Sized + Read, W: ?Sized + Write>(
+    short_crypt: &ShortCrypt,
+    reader: &mut R,
+    writer: &mut W,
+) -> io::Result<u64> {
+    let mut frame = vec![0; FRAME_LEN];
+
+    let mut total = 0;
+
+    loop {
+        // the stream ends with the final frame
+        let mut base = [0];
+
+        read_exact(reader, &mut base)?;
+
+        let is_final = base[0] & FINAL_FRAME != 0;
+
+        let len = if is_final {
+            let mut len = [0; 2];
+
+            read_exact(reader, &mut len)?;
+
+            usize::from(u16::from_be_bytes(len))
+        } else {
+            FRAME_LEN
+        };
+
+        let body = &mut frame[..len];
+
+        read_exact(reader, body)?;
+
+        short_crypt.decrypt_in_place(base[0] & !FINAL_FRAME, body).map_err(invalid_data)?;
+
+        writer.write_all(body)?;
+
+        total += len as u64;
+
+        if is_final {
+            // nothing may follow the final frame
+            if read_frame(reader, &mut [0])? != 0 {
+                return Err(invalid_data(ShortCryptError::InvalidLength));
+            }
+
+            break;
+        }
+    }
+
+    Ok(total)
+}
@@ -7,17 +7,28 @@ The most important thing is that a cipher is only **4 bits** larger than its pla
 
 ## Examples
 
-`encrypt` method can create a `Cipher` tuple separating into a **base** and a **body** of the cipher. The size of a **base** is 4 bits, and the size of a **body** is equal to the plaintext.
+`encrypt` method can create a `Cipher` separating into a **base** and a **body** of the cipher. The size of a **base** is 4 bits, and the size of a **body** is equal to the plaintext. `Cipher::new` refuses a **base** larger than `31`, and a `Cipher` can be encoded later with its `to_url_component` and `to_qr_code_alphanumeric` methods. `decrypt` also accepts a borrowed `CipherRef`, so a **body** held in a slice does not have to be copied, and `decrypt_slice` takes the **base** and such a slice directly. To avoid the heap entirely, `encrypt_into` writes the **body** into a caller-provided slice and returns the **base**. `decrypt_into` does the opposite and returns the length of the plaintext. For fixed-size records, `encrypt_array` and `decrypt_array` take and return `[u8; N]` directly. `encrypt_in_place` and `decrypt_in_place` work on a single mutable buffer, so large data is not duplicated. Likewise, `decrypt_vec` takes an owned **body** and returns the plaintext in the same allocation.
+
+When the plaintext arrives in chunks (e.g. from a socket), `encryptor` creates an `Encryptor` which takes the chunks by `update` (or `std::io::Write` with the `std` feature) and produces the same cipher by `finalize`, `finalize_to_url_component` or `finalize_to_qr_code_alphanumeric`. Its mirror, `decryptor`, creates a `Decryptor` which buffers cipher bytes or a text split across frames and decrypts them by `finalize`, `finalize_url_component` or `finalize_qr_code_alphanumeric`.
+
+To encrypt many items at once, e.g. the IDs of a sitemap, `encrypt_batch` returns a `Cipher` for each item, and `encrypt_batch_to_url_component` and `encrypt_batch_to_qr_code_alphanumeric` return the texts. The string variants reuse one scratch buffer for the **bodies** of all the items, so only the returned strings are allocated.
+
+Long-running servers can allocate an `EncryptScratch` or a `DecryptScratch` once and pass it to `encrypt_with_scratch`, `encrypt_to_url_component_with_scratch`, `encrypt_to_qr_code_alphanumeric_with_scratch` or `decrypt_with_scratch`, `decrypt_url_component_with_scratch`, `decrypt_qr_code_alphanumeric_with_scratch` for every request. The results borrow the buffers of the scratch, which are reused instead of being allocated for every cipher.
+
+To encrypt or decrypt many items, the `ShortCryptIteratorExt` trait adds `short_encrypt_url_components` / `short_decrypt_url_components` and `short_encrypt_qr_code_alphanumerics` / `short_decrypt_qr_code_alphanumerics` to every iterator. They map the items lazily, so no intermediate collection is needed. For a single value, the `ShortCryptExt` trait adds `encrypt_url_component` / `decrypt_url_component` and `encrypt_qr_code_alphanumeric` / `decrypt_qr_code_alphanumeric` to `str`, `[u8]` and the other `AsRef<[u8]>` types, e.g. `"articles".encrypt_url_component(&sc)`.
 
 ```rust
 extern crate short_crypt;
 
-use short_crypt::ShortCrypt;
+use short_crypt::{Cipher, ShortCrypt};
 
 let sc = ShortCrypt::new("magickey");
 
-assert_eq!((8, [216, 78, 214, 199, 157, 190, 78, 250].to_vec()), sc.encrypt("articles"));
-assert_eq!("articles".as_bytes().to_vec(), sc.decrypt(&(8, vec![216, 78, 214, 199, 157, 190, 78, 250])).unwrap());
+let cipher = sc.encrypt("articles");
+
+assert_eq!(8, cipher.base());
+assert_eq!(&[216, 78, 214, 199, 157, 190, 78, 250], cipher.body());
+assert_eq!("articles".as_bytes().to_vec(), sc.decrypt(&Cipher::new(8, vec![216, 78, 214, 199, 157, 190, 78, 250]).unwrap()).unwrap());
 
 ```
 
@@ -47,7 +58,97 @@ assert_eq!("3BHNNR45XZH8PU", sc.encrypt_to_qr_code_alphanumeric("articles"));
 assert_eq!("articles".as_bytes().to_vec(), sc.decrypt_qr_code_alphanumeric("3BHNNR45XZH8PU").unwrap());
 ```
 
-Besides, in order to reduce the copy times of strings, you can also use `encrypt_to_url_component_and_push_to_string`, `encrypt_to_qr_code_alphanumeric_and_push_to_string` methods to use the same memory space.
+Other text formats are also available. Each of them embeds the **base** into the encoded **body** in the same keyed way.
+
+* `encrypt_to_base58` / `decrypt_base58`: Base58, which avoids visually ambiguous characters such as `0`, `O`, `I` and `l`.
+* `encrypt_to_base62` / `decrypt_base62`: Base62, which is strictly alphanumeric.
+* `encrypt_to_crockford32` / `decrypt_crockford32`: Crockford Base32, which excludes confusable characters and is decoded case-insensitively.
+* `encrypt_to_hex` / `decrypt_hex`: lowercase hexadecimal. The **base** takes two digits.
+* `encrypt_to_qr_code_numeric` / `decrypt_qr_code_numeric`: digits only, which is denser than the alphanumeric mode in a QR code. The **base** takes two digits.
+* `encrypt_to_z_base_32` / `decrypt_z_base_32`: z-base-32, whose alphabet is designed to be easy for humans to read and transcribe.
+* `encrypt_to_base85` / `decrypt_base85`: Base85 (Ascii85), which is denser than Base64-URL but uses a wide range of printable ASCII characters.
+* `encrypt_to_base45` / `decrypt_base45`: Base45 (RFC 9285), which is used by many QR code pipelines.
+* `encrypt_to_custom_alphabet` / `decrypt_custom_alphabet`: a user-defined `Alphabet` with 32 or 64 characters.
+* `encrypt_to_emoji` / `decrypt_emoji`: one emoji per byte, for shareable tokens in chat applications.
+* `encrypt_to_words` / `decrypt_words`: words in the English word list of BIP-0039 joined with `-`, which are easy to dictate.
+* `encrypt_to_base64` / `decrypt_base64`: the standard Base64 alphabet with `=` padding.
+* `encrypt_to_dns_label` / `decrypt_dns_label`: a DNS label made of lowercase letters and digits, which can be used as a part of a hostname.
+* `encrypt_to_proquint` / `decrypt_proquint`: pronounceable five-letter words (proquints) joined with `-`, which are easy to read aloud.
+* `encrypt_to_bech32` / `decrypt_bech32`: Bech32 with a human-readable prefix and a BCH checksum which detects typos.
+* `encrypt_to_base26` / `decrypt_base26`: uppercase letters only, for codes read over the phone. The **base** takes two letters.
+* `encrypt_to_uuid` / `decrypt_uuid`: an RFC 9562 version 8 UUID string, for plaintexts no longer than 14 bytes.
+* `encrypt_to_code39` / `decrypt_code39`: the Code 39 barcode character set (without the space), so the text can be printed as a 1D barcode.
+* `encrypt_to_luhn_numeric` / `decrypt_luhn_numeric`: digits only with a Luhn check digit, which is validated before decryption.
+* `encrypt_to_serial` / `decrypt_serial`: the QR code alphanumeric text split into groups like `3BHN-NR45-XZH8-PU`, for serial numbers.
+* `encrypt_to_base36` / `decrypt_base36`: digits and uppercase letters, which is decoded case-insensitively for systems that change the case of identifiers.
+* `encrypt_to_base32_lowercase` / `decrypt_base32_lowercase`: the QR code alphanumeric text in lowercase, for channels which lowercase everything. It is decoded case-insensitively.
+* `encrypt_to_base32hex` / `decrypt_base32hex`: Base32 with the extended hex alphabet (RFC 4648). `Base32HexEncoding` can also be passed to `encrypt_to` / `decrypt_from`.
+* `encrypt_to_qr_code_kanji` / `decrypt_qr_code_kanji`: Shift JIS kanji bytes for the kanji mode of a QR code, which is denser than the alphanumeric mode.
+
+```rust
+extern crate short_crypt;
+
+use short_crypt::ShortCrypt;
+
+let sc = ShortCrypt::new("magickey");
+
+assert_eq!("dBTaSahCE9t1", sc.encrypt_to_base58("articles"));
+assert_eq!("articles".as_bytes().to_vec(), sc.decrypt_base58("dBTaSahCE9t1").unwrap());
+```
+
+To use your own encoding, implement the `OutputEncoding` trait and pass it to `encrypt_to` / `decrypt_from`. The built-in `UrlComponentEncoding` and `QrCodeAlphanumericEncoding` implement it too.
+
+A `ShortCrypt` instance is small and implements `Copy`, `Eq` and `Hash`, so it can live in config structs or be used as a map key. `ShortCrypt::new("")` succeeds, but `ShortCrypt::try_new` rejects an empty key, and `ShortCrypt::try_new_with_min_entropy` also rejects keys whose roughly estimated entropy is below a threshold in bits. Keys do not have to be text. `ShortCrypt::new_bytes` takes a binary key, such as a random 32-byte secret or the output of HKDF, and gives the same instance as `ShortCrypt::new` for a UTF-8 key. The state derived from a key can be exported by `to_state` as 16 bytes and restored by `from_state`, so it does not need to be re-derived on every cold start. For ports in other languages and FFI layers, the advanced `from_parts` / `to_parts` pair exposes the same state as the hashed key and the reversed key sum. For firmware, `ShortCrypt::new_const` derives the key in a const context, e.g. `static SC: ShortCrypt = ShortCrypt::new_const(b"magickey");`.
+
+To rotate keys, a `KeyRing` encrypts with its **primary** key and decrypts with every key in order, returning the index of the key which was accepted, so tokens issued with older keys keep working for a while. A cipher has no authentication tag, and a key is only accepted when the **base** recomputed from the plaintext matches, so a wrong key still has about a 1/32 chance to be accepted.
+
+With many keys, `encrypt_to_url_component_with_key_id` and `encrypt_to_qr_code_alphanumeric_with_key_id` prefix the text with one character representing a key ID from `0` to `31`, and a `KeyedDecoder` picks the key by that ID instead of trying every key.
+
+Texts copied from emails or printed material often contain spaces, hyphens or line breaks. After calling `set_ignore_separators(true)`, the `decrypt_*` methods strip them before locating the **base**. The same option can be set when the instance is created, by `ShortCrypt::builder(key).ignore_separators(true).build()`.
+
+The builder has more options. `format` picks the format used by `encrypt_to_text` and `decrypt_text`, `base_placement` puts the **base** at the start or the end of a text instead of a keyed position, `padding(false)` drops the `=` padding of Base64 texts, and `checksum(true)` makes decryption check the plaintext against its **base** and return `ShortCryptError::InvalidChecksum` for most tampered texts.
+
+If a text may come from several formats, `decrypt_any` recognizes the format by its characters and returns the `Format` along with the plaintext.
+
+If the plaintext is text, `decrypt_url_component_to_string` and `decrypt_qr_code_alphanumeric_to_string` validate it as UTF-8 and return a `String`.
+
+For links such as password resets, `encrypt_with_ttl` embeds an expiration time into a URL component, and `decrypt_timed` rejects the token once it has expired. The current time is passed by the caller, so no clock is needed in `no_std` environments.
+
+To carry several values in one token, `encrypt_fields` / `decrypt_fields` (and their URL component and QR code alphanumeric variants) prefix each field with its length, so no delimiter is needed.
+
+`EncryptedId<T>` wraps an integer ID whose type implements `EncryptedIdKey`. It is formatted as an encrypted URL component by `Display` and parsed back by `FromStr`, so web handlers can accept typed IDs such as `EncryptedId<UserId>`.
+
+A `TokenMap` holds a few string key-value pairs, such as the configuration of a device, and can be encrypted into one token by `encrypt_token_map_to_url_component` or `encrypt_token_map_to_qr_code_alphanumeric`.
+
+For permission or feature-flag links, a `Capability` packs a subject ID and a `u64` bitmask, and can be encrypted by `encrypt_capability_to_url_component` / `decrypt_url_component_to_capability`.
+
+The `cursor` module provides a `Cursor` for pagination, which can be encrypted into an opaque URL component by `encrypt_cursor` and validated by `decrypt_cursor`.
+
+Integers can be encrypted without converting them to bytes manually, by using `encrypt_u64` / `decrypt_u64`, `encrypt_u128` / `decrypt_u128` and their URL component and QR code alphanumeric variants such as `encrypt_u64_to_url_component` / `decrypt_url_component_to_u64`. `encrypt_u64_varint` / `decrypt_u64_varint` encode the integer in LEB128 first, so small integers produce short ciphers, and `encrypt_i64` / `decrypt_i64` do the same for signed integers with zigzag encoding. `encrypt_f64` / `decrypt_f64` round-trip the bit pattern of a floating-point number.
+
+With the `uuid` feature enabled, UUIDs can be encrypted by `encrypt_uuid` / `decrypt_to_uuid` and their text variants such as `encrypt_uuid_to_url_component` / `decrypt_url_component_to_uuid` and `encrypt_uuid_to` / `decrypt_from_to_uuid`.
+
+IP addresses can be encrypted by `encrypt_ipv4` / `decrypt_ipv4` and `encrypt_ipv6` / `decrypt_ipv6`, along with their URL component and QR code alphanumeric variants, for privacy-preserving logs and URL parameters. MAC addresses can be encrypted by `encrypt_mac` / `decrypt_mac` and their variants, for device provisioning QR codes.
+
+With the `chrono` or `time` feature enabled, `DateTime<Utc>` and `OffsetDateTime` values can be encrypted into compact tokens as timestamps in seconds or milliseconds (`TimestampPrecision`), by `encrypt_date_time` / `decrypt_date_time` and `encrypt_offset_date_time` / `decrypt_offset_date_time` along with their URL component variants.
+
+With the `std` feature enabled, `ShortCrypt::from_key_file` reads a key from a file and trims its trailing newlines, and `ShortCrypt::from_key_file_checked` also rejects a key file which can be accessed by other users on Unix. The failures are reported as `KeyFileError`. `encrypt_large` / `decrypt_large` process a multi-megabyte payload from a `Read` to a `Write` with the memory of one frame, in a format where every `ShortCrypt::LARGE_FRAME_LEN` bytes of the plaintext are encrypted as a separate cipher with its own **base**. `encrypt_path` / `decrypt_to_path_buf` encrypt a filesystem path into a URL component, including non-UTF-8 paths on Unix and Windows.
+
+With the `global` feature enabled, the `global` module keeps one process-wide instance, which is set once by `short_crypt::global::init(key)` and used by free functions such as `short_crypt::global::encrypt_url_component` / `short_crypt::global::decrypt_url_component`, so an application with a single key does not need to pass `&ShortCrypt` through every layer.
+
+With the `heapless` feature enabled, `encrypt_to_url_component_heapless` / `decrypt_url_component_heapless` and `encrypt_to_qr_code_alphanumeric_heapless` / `decrypt_qr_code_alphanumeric_heapless` produce and consume `heapless::String<N>` and `heapless::Vec<u8, N>` without touching the heap, for firmware with static memory only.
+
+With the `zeroize` feature enabled, `ShortCrypt` implements `Zeroize` to wipe the state derived from the key. Since it is `Copy`, use `zeroize::Zeroizing<ShortCrypt>` to wipe it on drop. `Encryptor` and `Decryptor` implement `ZeroizeOnDrop`, so their buffered plaintexts are wiped when they are dropped.
+
+With the `simd` feature enabled, the keystream XOR and the XOR/sum folding of encryption and decryption process 16 bytes per iteration with SSE2 on x86/x86_64 or NEON on AArch64, which speeds up long plaintexts. The ciphers are exactly the same, and other targets keep using the scalar code.
+
+With the `rayon` feature enabled, `par_encrypt_to_url_components` / `par_decrypt_url_components` and `par_encrypt_to_qr_code_alphanumerics` / `par_decrypt_qr_code_alphanumerics` split a large batch, such as a whole ID column of an ETL job, across the rayon thread pool. The results are in the same order as the input.
+
+With the `serde` feature enabled, any value which implements `Serialize` can be serialized with postcard and encrypted by `encrypt_value` / `decrypt_value`, `encrypt_value_to_url_component` / `decrypt_url_component_to_value` and `encrypt_value_to_qr_code_alphanumeric` / `decrypt_qr_code_alphanumeric_to_value`, so a structured payload can become a single token. `Cipher` also implements `Serialize` and `Deserialize` as a struct with `base` and `body` fields, and a **base** larger than `31` is rejected when deserializing. For fields, the `short_crypt::serde` module provides `url_component` and `qr_code_alphanumeric` helpers for `serialize_with` / `deserialize_with`, and a `ShortCryptString<K>` wrapper. They take the key from an `EncryptedIdKey` implementation, and `EncryptedId` is serialized as its encrypted text too.
+
+All the decrypting methods return a `ShortCryptError` when they fail. They never panic on any input, so untrusted texts such as query strings can be passed to them directly. The variant tells the reason, such as `InvalidCharacter`, `InvalidLength` or `InvalidBase`, and `ShortCryptError::index` returns the byte index of an invalid character in the text, so validation messages can point at it. It implements `Display` and `core::error::Error`, and can be converted into a `&'static str`.
+
+Besides, in order to reduce the copy times of strings, you can also use `encrypt_to_url_component_and_push_to_string`, `encrypt_to_qr_code_alphanumeric_and_push_to_string` methods to use the same memory space. When the buffer lives in a struct, the `encrypt_to_url_component_append` and `encrypt_to_qr_code_alphanumeric_append` methods append to a `&mut String`, and the `decrypt_url_component_append` and `decrypt_qr_code_alphanumeric_append` methods append to a `&mut Vec<u8>`. For byte buffers such as HTTP response bodies, the `encrypt_to_url_component_and_push_to_vec` and `encrypt_to_qr_code_alphanumeric_and_push_to_vec` methods push the ASCII text to a `Vec<u8>` without going through a `String`. The `encrypt_to_url_component_into` and `encrypt_to_qr_code_alphanumeric_into` methods push the text to any `OutputBuffer`, which is implemented for `String` and `Vec<u8>`, for `heapless::String` and `heapless::Vec<u8, N>` with the `heapless` feature, for `arrayvec::ArrayString` with the `arrayvec` feature, and for `bytes::BytesMut` with the `bytes` feature, so tokio or hyper services can write tokens into their buffers directly (a `Bytes` input works anywhere since it implements `AsRef<[u8]>`). A fixed-capacity buffer which is too small is left unchanged and `ShortCryptError::BufferTooSmall` is returned. To compose a text with several encrypted segments, a `TokenWriter` wraps such a buffer and chains `push_literal`, `push_url_component` and `push_qr_code_alphanumeric` calls. The const functions `ShortCrypt::url_component_len` and `ShortCrypt::qr_code_alphanumeric_len` return the exact length of the text for a plaintext length, to pre-size buffers or pick a QR code version. The `encrypt_to_url_component_writer` and `encrypt_to_qr_code_alphanumeric_writer` methods can write the text into any `core::fmt::Write` target, such as a fixed-capacity string, without allocating a `String`, and the `display_url_component` and `display_qr_code_alphanumeric` methods return values implementing `Display` which do the same inside `format!` or `write!`. The `encrypt_to_url_component_buf` and `encrypt_to_qr_code_alphanumeric_buf` methods write the text into a byte buffer. The `encrypt_to_url_component_chars`, `encrypt_to_qr_code_alphanumeric_chars` and `encrypt_to_custom_alphabet_chars` methods return an `EncryptedChars` iterator which encodes the characters lazily.
 
 ```rust
 extern crate short_crypt;
@@ -70,87 +171,223 @@ assert_eq!("https://magiclen.org/3BHNNR45XZH8PU", sc.encrypt_to_qr_code_alphanum
 
 #[macro_use]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub extern crate base32;
 pub extern crate base64_url;
 
-use alloc::{string::String, vec::Vec};
-use core::fmt::{self, Debug, Formatter};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    net::{Ipv4Addr, Ipv6Addr},
+};
 
+use base64::Engine;
 pub use base64_url::base64;
 use crc_any::{CRCu64, CRCu8};
 
-/// A tuple. The first `u8` value is the **base** which only takes 4 bits. The second `Vec<u8>` value is the **body** whose size is equal to the plaintext. You can use your own algorithms to combine them together, or just use `encrypt_to_url_component` or `encrypt_to_qr_code_alphanumeric` to output them as a random-like string.
-pub type Cipher = (u8, Vec<u8>);
+mod alphabet;
+mod ascii85;
+mod base45;
+mod base_placement;
+mod bech32;
+mod bits;
+mod builder;
+mod capability;
+mod chars;
+mod cipher;
+mod crc;
+mod display;
+mod encoding;
+mod encrypted_id;
+mod error;
+mod ext;
+mod fields;
+mod format;
+mod inline_buffer;
+mod iter;
+mod kanji;
+#[cfg(feature = "std")]
+mod key_file;
+mod key_id;
+mod key_ring;
+mod keystream;
+#[cfg(feature = "std")]
+mod large;
+mod output;
+#[cfg(feature = "std")]
+mod path;
+mod proquint;
+mod radix;
+mod scratch;
+mod stream;
+mod timestamp;
+mod token_map;
+mod token_writer;
+mod varint;
+mod words;
+
+pub mod cursor;
+#[cfg(feature = "global")]
+pub mod global;
+#[cfg(feature = "serde")]
+pub mod serde;
+
+pub use alphabet::Alphabet;
+pub use base_placement::BasePlacement;
+pub use builder::ShortCryptBuilder;
+pub use capability::Capability;
+pub use chars::EncryptedChars;
+pub use cipher::{Cipher, CipherRef};
+pub use display::{DisplayQrCodeAlphanumeric, DisplayUrlComponent};
+pub use encoding::{
+    Base32HexEncoding, OutputEncoding, QrCodeAlphanumericEncoding, UrlComponentEncoding,
+};
+use encoding::{
+    BitsEncoding, BASE32_ALPHABET, BASE_DECODING_TABLE, BASE_SYMBOLS, URL_COMPONENT_ALPHABET,
+};
+pub use encrypted_id::{EncryptedId, EncryptedIdKey};
+pub use error::ShortCryptError;
+pub use ext::ShortCryptExt;
+pub use format::Format;
+use inline_buffer::InlineBuffer;
+pub use iter::ShortCryptIteratorExt;
+#[cfg(feature = "std")]
+pub use key_file::KeyFileError;
+pub use key_id::KeyedDecoder;
+pub use key_ring::KeyRing;
+pub use output::OutputBuffer;
+use output::OutputWriter;
+pub use scratch::{DecryptScratch, EncryptScratch};
+pub use stream::{Decryptor, Encryptor};
+pub use timestamp::TimestampPrecision;
+pub use token_map::TokenMap;
+pub use token_writer::TokenWriter;
+
+/// Pack a `Cipher` into bytes. The first byte is the **base** and the rest bytes are the **body**, so the packed data is only one byte larger than the plaintext.
+pub fn cipher_to_bytes(cipher: &Cipher) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1 + cipher.body.len());
+
+    bytes.push(cipher.base);
+    bytes.extend_from_slice(&cipher.body);
+
+    bytes
+}
+
+/// Unpack bytes created by `cipher_to_bytes` into a `Cipher`. Empty bytes have no **base** and are rejected.
+pub fn cipher_from_bytes<T: ?Sized + AsRef<[u8]>>(bytes: &T) -> Result<Cipher, ShortCryptError> {
+    match bytes.as_ref().split_first() {
+        Some((base, body)) => Cipher::new(*base, body.to_vec()),
+        None => Err(ShortCryptError::InvalidLength),
+    }
+}
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ShortCrypt {
-    hashed_key:  [u8; 8],
-    key_sum_rev: u64,
+    hashed_key:        [u8; 8],
+    key_sum_rev:       u64,
+    ignore_separators: bool,
+    base_placement:    BasePlacement,
+    format:            Format,
+    padding:           bool,
+    checksum:          bool,
+}
+
+/// Wipe the state derived from the key. `ShortCrypt` implements `Copy`, so it cannot wipe itself on drop; wrap it in `zeroize::Zeroizing` for that.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ShortCrypt {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.hashed_key.zeroize();
+        self.key_sum_rev.zeroize();
+    }
 }
 
 impl Debug for ShortCrypt {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        debug_helper::impl_debug_for_struct!(ShortCrypt, f, self, let .hashed_key = self.hashed_key.as_ref(), (.key_sum_rev, "{:X}", self.key_sum_rev));
+        debug_helper::impl_debug_for_struct!(ShortCrypt, f, self, let .hashed_key = self.hashed_key.as_ref(), (.key_sum_rev, "{:X}", self.key_sum_rev), .ignore_separators, .base_placement, .format, .padding, .checksum);
     }
 }
 
-macro_rules! u8_to_string_64 {
-    ($i:expr) => {
-        if $i < 10 {
-            $i + b'0'
-        } else if (10..36).contains(&$i) {
-            $i - 10 + b'A'
-        } else if (36..62).contains(&$i) {
-            $i - 36 + b'a'
-        } else if $i == 62 {
-            b'-'
-        } else {
-            b'_'
-        }
-    };
-}
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE58_DECODING_TABLE: [u8; 256] = radix::decoding_table(BASE58_ALPHABET);
 
-macro_rules! string_64_to_u8 {
-    ($c:expr) => {
-        if $c >= b'0' && $c <= b'9' {
-            $c - b'0'
-        } else if $c >= b'A' && $c <= b'Z' {
-            $c + 10 - b'A'
-        } else if $c >= b'a' && $c <= b'z' {
-            $c + 36 - b'a'
-        } else if $c == b'-' {
-            62
-        } else {
-            63
-        }
-    };
-}
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE62_DECODING_TABLE: [u8; 256] = radix::decoding_table(BASE62_ALPHABET);
 
-macro_rules! u8_to_string_32 {
-    ($i:expr) => {
-        if $i < 10 {
-            $i + b'0'
-        } else {
-            $i - 10 + b'A'
-        }
-    };
-}
+const CROCKFORD32_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const CROCKFORD32_DECODING_TABLE: [u8; 256] = {
+    let mut table = radix::decoding_table(CROCKFORD32_ALPHABET);
 
-macro_rules! string_32_to_u8 {
-    ($c:expr) => {
-        if $c >= b'0' && $c <= b'9' {
-            $c - b'0'
-        } else {
-            $c + 10 - b'A'
-        }
-    };
-}
+    let mut i = 0;
+
+    while i < CROCKFORD32_ALPHABET.len() {
+        table[CROCKFORD32_ALPHABET[i].to_ascii_lowercase() as usize] = i as u8;
+
+        i += 1;
+    }
+
+    // confusable characters
+    table[b'O' as usize] = 0;
+    table[b'o' as usize] = 0;
+    table[b'I' as usize] = 1;
+    table[b'i' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'l' as usize] = 1;
+
+    table
+};
+
+const DECIMAL_ALPHABET: &[u8; 10] = b"0123456789";
+const DECIMAL_DECODING_TABLE: [u8; 256] = radix::decoding_table(DECIMAL_ALPHABET);
+
+const Z_BASE_32_ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+const Z_BASE_32_DECODING_TABLE: [u8; 256] = radix::decoding_table(Z_BASE_32_ALPHABET);
+
+const BASE85_DECODING_TABLE: [u8; 256] = radix::decoding_table(&ascii85::ALPHABET);
+
+const BASE45_DECODING_TABLE: [u8; 256] = radix::decoding_table(base45::ALPHABET);
+
+/// The emoji alphabet is the 256 code points from U+1F400 (🐀) to U+1F4FF (📿).
+const EMOJI_START: u32 = 0x1F400;
+
+const DNS_LABEL_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const DNS_LABEL_DECODING_TABLE: [u8; 256] = radix::decoding_table(DNS_LABEL_ALPHABET);
+
+const BECH32_DECODING_TABLE: [u8; 256] = radix::decoding_table(bech32::ALPHABET);
+
+const BASE26_ALPHABET: &[u8; 26] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const BASE26_DECODING_TABLE: [u8; 256] = radix::decoding_table(BASE26_ALPHABET);
+
+const BASE36_ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const BASE36_DECODING_TABLE: [u8; 256] = radix::decoding_table(BASE36_ALPHABET);
+
+/// The Code 39 character set without the space.
+const CODE39_ALPHABET: &[u8; 42] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ-.$/+%";
+const CODE39_DECODING_TABLE: [u8; 256] = radix::decoding_table(CODE39_ALPHABET);
+
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+const HEX_DECODING_TABLE: [u8; 256] = radix::decoding_table(HEX_ALPHABET);
+
+const URL_COMPONENT_DECODING_TABLE: [u8; 256] = radix::decoding_table(URL_COMPONENT_ALPHABET);
+const BASE32_DECODING_TABLE: [u8; 256] = radix::decoding_table(BASE32_ALPHABET);
 
 impl ShortCrypt {
+    /// The length of the plaintext of a frame created by `encrypt_large`, except the last one, which is shorter.
+    pub const LARGE_FRAME_LEN: usize = 64 * 1024;
+
     /// Create a new ShortCrypt instance.
+    #[inline]
     pub fn new<S: AsRef<str>>(key: S) -> ShortCrypt {
-        let key_bytes = key.as_ref().as_bytes();
+        Self::new_bytes(key.as_ref())
+    }
+
+    /// Create a `ShortCrypt` instance with a binary key, such as a random secret or the output of a KDF. A UTF-8 key gives the same instance as `new`.
+    pub fn new_bytes<K: AsRef<[u8]>>(key: K) -> ShortCrypt {
+        let key_bytes = key.as_ref();
 
         let hashed_key = {
             let mut hasher = CRCu64::crc64we();
@@ -171,423 +408,3625 @@ impl ShortCrypt {
         ShortCrypt {
             hashed_key,
             key_sum_rev,
+            ignore_separators: false,
+            base_placement: BasePlacement::Keyed,
+            format: Format::UrlComponent,
+            padding: true,
+            checksum: false,
         }
     }
 
-    pub fn encrypt<T: ?Sized + AsRef<[u8]>>(&self, plaintext: &T) -> Cipher {
-        let data = plaintext.as_ref();
-
-        let len = data.len();
-
-        let hashed_value = {
-            let mut crc8 = CRCu8::crc8cdma2000();
-
-            crc8.digest(data);
-            crc8.get_crc()
-        };
-
-        let base = hashed_value % 32;
+    /// Same as `new_bytes`, but an empty key, which produces trivially guessable tokens, is rejected.
+    #[inline]
+    pub fn try_new<K: AsRef<[u8]>>(key: K) -> Result<ShortCrypt, ShortCryptError> {
+        Self::try_new_with_min_entropy(key, 0)
+    }
 
-        let mut encrypted = Vec::with_capacity(len);
+    /// Same as `try_new`, but the estimated entropy of the key must be at least `min_bits` bits. The estimate is the length of the key multiplied by the rounded-down base-2 logarithm of the number of distinct bytes in it, e.g. 24 bits for `"magickey"` and about 128 bits for 32 random bytes. It only catches obviously weak keys such as `"aaaaaaaa"` or short words, and cannot prove that a key is strong.
+    pub fn try_new_with_min_entropy<K: AsRef<[u8]>>(
+        key: K,
+        min_bits: u32,
+    ) -> Result<ShortCrypt, ShortCryptError> {
+        let key = key.as_ref();
 
-        let mut m = base;
-        let mut sum = u64::from(base);
+        if key.is_empty() {
+            return Err(ShortCryptError::EmptyKey);
+        }
 
-        for (i, d) in data.iter().enumerate() {
-            let offset = self.hashed_key[i % 8] ^ base;
+        let mut seen = [false; 256];
+        let mut distinct = 0u32;
 
-            let v = d ^ offset;
+        for n in key.iter().copied() {
+            if !seen[n as usize] {
+                seen[n as usize] = true;
+                distinct += 1;
+            }
+        }
 
-            encrypted.push(v);
+        let bits = (key.len() as u64).saturating_mul(u64::from(distinct.ilog2()));
 
-            m ^= v;
-            sum = sum.wrapping_add(u64::from(v));
+        if bits < u64::from(min_bits) {
+            return Err(ShortCryptError::WeakKey);
         }
 
-        let sum: [u8; 8] = sum.to_be_bytes();
+        Ok(Self::new_bytes(key))
+    }
 
-        let hashed_array: [u8; 8] = {
-            let mut hasher = CRCu64::crc64we();
+    /// Same as `new_bytes`, but the key is derived in a const context, so an instance can be baked into a `static` without any runtime initialization.
+    pub const fn new_const(key: &[u8]) -> ShortCrypt {
+        let hashed_key = crc::crc64we(key).to_be_bytes();
 
-            hasher.digest(&[m]);
-            hasher.digest(&sum);
+        let mut key_sum = 0u64;
 
-            hasher.get_crc().to_be_bytes()
-        };
+        let mut i = 0;
 
-        let mut path = Vec::with_capacity(len);
+        while i < key.len() {
+            key_sum = key_sum.wrapping_add(key[i] as u64);
 
-        for i in 0..len {
-            let index = i % 8;
-            path.push((hashed_array[index] ^ self.hashed_key[index]) as usize % len);
+            i += 1;
         }
 
-        for (i, p) in path.iter().copied().enumerate() {
-            if i == p {
-                continue;
-            }
-
-            encrypted.swap(i, p);
+        ShortCrypt {
+            hashed_key,
+            key_sum_rev: key_sum.reverse_bits(),
+            ignore_separators: false,
+            base_placement: BasePlacement::Keyed,
+            format: Format::UrlComponent,
+            padding: true,
+            checksum: false,
         }
+    }
 
-        (base, encrypted)
+    /// Create a `ShortCryptBuilder` with a text or binary key.
+    #[inline]
+    pub fn builder<K: AsRef<[u8]>>(key: K) -> ShortCryptBuilder {
+        ShortCryptBuilder::new(Self::new_bytes(key))
     }
 
-    pub fn decrypt(&self, data: &Cipher) -> Result<Vec<u8>, &'static str> {
-        let base = data.0;
-        let data = &data.1;
+    /// Export the state derived from the key, so it can be cached and restored by `from_state` without the key. The first 8 bytes are the hashed key and the rest are the reversed key sum in big-endian. Whether separators are ignored is not included.
+    #[inline]
+    pub fn to_state(&self) -> [u8; 16] {
+        let mut state = [0; 16];
 
-        if base > 31 {
-            return Err("The base is not correct.");
-        }
+        state[..8].copy_from_slice(&self.hashed_key);
+        state[8..].copy_from_slice(&self.key_sum_rev.to_be_bytes());
 
-        let len = data.len();
+        state
+    }
 
-        let mut decrypted = Vec::with_capacity(len);
+    /// Restore an instance from the state exported by `to_state`.
+    #[inline]
+    pub fn from_state(state: [u8; 16]) -> ShortCrypt {
+        let mut hashed_key = [0; 8];
+        let mut key_sum_rev = [0; 8];
 
-        self.decrypt_inner(base, data, &mut decrypted);
+        hashed_key.copy_from_slice(&state[..8]);
+        key_sum_rev.copy_from_slice(&state[8..]);
 
-        Ok(decrypted)
+        ShortCrypt::from_parts(hashed_key, u64::from_be_bytes(key_sum_rev))
     }
 
-    fn decrypt_inner(&self, base: u8, data: &[u8], output: &mut Vec<u8>) {
-        let len = data.len();
-
-        let mut m = base;
-        let mut sum = u64::from(base);
-
-        for v in data.iter().copied() {
-            m ^= v;
-            sum = sum.wrapping_add(u64::from(v));
+    /// **Advanced.** Create an instance from the derived key material directly: `hashed_key` is the CRC-64/WE of the key in big-endian, and `key_sum_rev` is the wrapping sum of the key bytes with its bits reversed. It is meant for ports and FFI layers which must share exactly the same state, and for tests which pin the internal values. Any other values produce an instance which no key can reproduce.
+    #[inline]
+    pub const fn from_parts(hashed_key: [u8; 8], key_sum_rev: u64) -> ShortCrypt {
+        ShortCrypt {
+            hashed_key,
+            key_sum_rev,
+            ignore_separators: false,
+            base_placement: BasePlacement::Keyed,
+            format: Format::UrlComponent,
+            padding: true,
+            checksum: false,
         }
+    }
 
-        let sum: [u8; 8] = sum.to_be_bytes();
+    /// **Advanced.** Get the derived key material accepted by `from_parts`, as `(hashed_key, key_sum_rev)`.
+    #[inline]
+    pub const fn to_parts(&self) -> ([u8; 8], u64) {
+        (self.hashed_key, self.key_sum_rev)
+    }
 
-        let hashed_array: [u8; 8] = {
-            let mut hasher = CRCu64::crc64we();
+    /// Set whether the `decrypt_*` methods for texts should ignore separators (spaces, tabs, line breaks and hyphens) before locating the base, so that texts copied from emails or printed material can still be decrypted. Hyphens are kept for the formats which use them as symbols or delimiters. The default value is `false`.
+    #[inline]
+    pub fn set_ignore_separators(&mut self, ignore_separators: bool) {
+        self.ignore_separators = ignore_separators;
+    }
 
-            hasher.digest(&[m]);
-            hasher.digest(&sum);
+    pub fn encrypt<T: ?Sized + AsRef<[u8]>>(&self, plaintext: &T) -> Cipher {
+        let data = plaintext.as_ref();
 
-            hasher.get_crc().to_be_bytes()
-        };
+        let mut encrypted = vec![0; data.len()];
 
-        let mut path = Vec::with_capacity(len);
+        let base = self.encrypt_to_slice(data, &mut encrypted);
 
-        for i in 0..len {
-            let index = i % 8;
-            path.push((hashed_array[index] ^ self.hashed_key[index]) as usize % len);
+        Cipher {
+            base,
+            body: encrypted,
         }
+    }
 
-        let mut data = data.to_vec();
-
-        for (i, p) in path.iter().copied().enumerate().rev() {
-            if i == p {
-                continue;
-            }
-
-            data.swap(i, p);
-        }
+    /// Encrypt a fixed-size record, e.g. a 12-byte sensor frame, without touching the heap. Returns the **base** and the **body**.
+    #[inline]
+    pub fn encrypt_array<const N: usize>(&self, plaintext: &[u8; N]) -> (u8, [u8; N]) {
+        let mut body = [0; N];
 
-        for (i, d) in data.iter().enumerate() {
-            let offset = self.hashed_key[i % 8] ^ base;
+        let base = self.encrypt_to_slice(plaintext, &mut body);
 
-            output.push(d ^ offset);
-        }
+        (base, body)
     }
 
-    pub fn encrypt_to_url_component<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
-        let (base, encrypted) = self.encrypt(data);
+    /// Encrypt `data` into an `InlineBuffer`, so a short **body** which is only encoded into a text does not need a heap allocation. Returns the **base** and the **body**.
+    #[inline]
+    fn encrypt_inline<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> (u8, InlineBuffer) {
+        let data = data.as_ref();
 
-        let base = u8_to_string_64!(base);
+        let mut encrypted = InlineBuffer::zeroed(data.len());
 
-        let base_char = base as char;
+        let base = self.encrypt_to_slice(data, &mut encrypted);
 
-        let mut result = String::with_capacity(1 + ((encrypted.len() * 4 + 2) / 3));
+        (base, encrypted)
+    }
 
-        base64_url::encode_to_string(&encrypted, &mut result);
+    /// Encrypt `plaintext` and write the **body** of the cipher into the beginning of `out`, without any heap allocation. Returns the **base**. `out` must not be shorter than `plaintext`.
+    #[inline]
+    pub fn encrypt_into(&self, plaintext: &[u8], out: &mut [u8]) -> Result<u8, ShortCryptError> {
+        let out = out.get_mut(..plaintext.len()).ok_or(ShortCryptError::BufferTooSmall)?;
 
-        let mut sum = u64::from(base);
+        Ok(self.encrypt_to_slice(plaintext, out))
+    }
 
-        for n in result.bytes() {
-            sum = sum.wrapping_add(u64::from(n));
-        }
+    /// Encrypt every item of `items`. This is equivalent to calling `encrypt` for each of them in a loop, so every `Cipher` owns its own **body**.
+    #[inline]
+    pub fn encrypt_batch<T: AsRef<[u8]>>(&self, items: &[T]) -> Vec<Cipher> {
+        items.iter().map(|item| self.encrypt(item)).collect()
+    }
 
-        let base_index = ((self.key_sum_rev ^ sum) % ((result.len() + 1) as u64)) as usize;
+    /// Encrypt every item of `items` into a URL component, like calling `encrypt_to_url_component` for each of them. One scratch buffer is reused for the **bodies** of all the items, so only the returned strings are allocated, each with its exact length.
+    #[inline]
+    pub fn encrypt_batch_to_url_component<T: AsRef<[u8]>>(&self, items: &[T]) -> Vec<String> {
+        self.encrypt_batch_bits(items, URL_COMPONENT_ALPHABET, 6)
+    }
 
-        result.insert(base_index, base_char);
+    /// Encrypt every item of `items` into a QR code alphanumeric text, like calling `encrypt_to_qr_code_alphanumeric` for each of them. One scratch buffer is reused for the **bodies** of all the items, so only the returned strings are allocated, each with its exact length.
+    #[inline]
+    pub fn encrypt_batch_to_qr_code_alphanumeric<T: AsRef<[u8]>>(
+        &self,
+        items: &[T],
+    ) -> Vec<String> {
+        self.encrypt_batch_bits(items, BASE32_ALPHABET, 5)
+    }
 
-        result
+    /// Encrypt every item of `items` into a URL component on the rayon thread pool. The texts are in the same order as `items`, and each thread reuses one scratch buffer for the **bodies**.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_encrypt_to_url_components<T: AsRef<[u8]> + Sync>(&self, items: &[T]) -> Vec<String> {
+        self.par_encrypt_bits(items, URL_COMPONENT_ALPHABET, 6)
     }
 
-    pub fn encrypt_to_url_component_and_push_to_string<T: ?Sized + AsRef<[u8]>, S: Into<String>>(
+    /// Decrypt every URL component of `items` on the rayon thread pool. The results are in the same order as `items`.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_decrypt_url_components<S: AsRef<str> + Sync>(
         &self,
-        data: &T,
-        output: S,
-    ) -> String {
-        let (base, encrypted) = self.encrypt(data);
+        items: &[S],
+    ) -> Vec<Result<Vec<u8>, ShortCryptError>> {
+        use rayon::prelude::*;
 
-        let base = u8_to_string_64!(base);
+        items.par_iter().map(|item| self.decrypt_url_component(item)).collect()
+    }
 
-        let base_char = base as char;
+    /// Encrypt every item of `items` into a QR code alphanumeric text on the rayon thread pool. The texts are in the same order as `items`, and each thread reuses one scratch buffer for the **bodies**.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_encrypt_to_qr_code_alphanumerics<T: AsRef<[u8]> + Sync>(
+        &self,
+        items: &[T],
+    ) -> Vec<String> {
+        self.par_encrypt_bits(items, BASE32_ALPHABET, 5)
+    }
 
-        let mut output = output.into();
+    /// Decrypt every QR code alphanumeric text of `items` on the rayon thread pool. The results are in the same order as `items`.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_decrypt_qr_code_alphanumerics<S: AsRef<str> + Sync>(
+        &self,
+        items: &[S],
+    ) -> Vec<Result<Vec<u8>, ShortCryptError>> {
+        use rayon::prelude::*;
 
-        let original_len = output.len();
+        items.par_iter().map(|item| self.decrypt_qr_code_alphanumeric(item)).collect()
+    }
 
-        base64_url::encode_to_string(&encrypted, &mut output);
+    /// Encrypt `bases.len()` plaintexts of the same length at once. They are stored column by column (struct-of-arrays), so the byte `j` of the plaintext `k` is `data[j * bases.len() + k]`. Each plaintext is replaced with the **body** of its cipher in the same layout, and its **base** is written to `bases[k]`.
+    ///
+    /// The ciphers are the same as the ones created by `encrypt`, but the CRC-8, the keystream XOR and the folding walk the plaintexts row by row, so the compiler can spread them across SIMD lanes, which suits millions of IDs of the same length. Only the permutation is done plaintext by plaintext. Returns `ShortCryptError::InvalidLength` if the length of `data` is not a multiple of `bases.len()`.
+    pub fn encrypt_columns_in_place(
+        &self,
+        data: &mut [u8],
+        bases: &mut [u8],
+    ) -> Result<(), ShortCryptError> {
+        let len = columns_len(data.len(), bases.len())?;
 
-        let mut sum = u64::from(base);
+        if len == 0 {
+            // the CRC-8 of an empty plaintext is its initial value
+            bases.fill(0xFF % 32);
 
-        for n in output.bytes().skip(original_len) {
-            sum = sum.wrapping_add(u64::from(n));
+            return Ok(());
         }
 
-        let base_index =
-            ((self.key_sum_rev ^ sum) % ((output.len() - original_len + 1) as u64)) as usize;
-
-        output.insert(original_len + base_index, base_char);
-
-        output
-    }
+        let lanes = bases.len();
 
-    pub fn decrypt_url_component<S: AsRef<str>>(
-        &self,
-        url_component: S,
-    ) -> Result<Vec<u8>, &'static str> {
-        let bytes = url_component.as_ref().as_bytes();
-        let len = bytes.len();
+        bases.fill(0xFF);
 
-        if len < 1 {
-            return Err("The URL component is incorrect.");
+        for row in data.chunks_exact(lanes) {
+            for (crc, n) in bases.iter_mut().zip(row.iter()) {
+                *crc = crc::CRC8_CDMA2000_TABLE[(*crc ^ *n) as usize];
+            }
         }
 
-        let base_index = {
-            let mut sum = 0u64;
+        for base in bases.iter_mut() {
+            *base %= 32;
+        }
 
-            for n in bytes.iter().copied() {
-                sum = sum.wrapping_add(u64::from(n));
-            }
+        let mut m = vec![0u8; lanes];
+        let mut sum = vec![0u64; lanes];
 
-            ((self.key_sum_rev ^ sum) % (len as u64)) as usize
-        };
+        for (j, row) in data.chunks_exact_mut(lanes).enumerate() {
+            let key = self.hashed_key[j % 8];
 
-        let base = string_64_to_u8!(bytes[base_index]);
+            for (((v, base), m), sum) in
+                row.iter_mut().zip(bases.iter()).zip(m.iter_mut()).zip(sum.iter_mut())
+            {
+                *v ^= key ^ base;
 
-        if base > 31 {
-            return Err("The URL component is incorrect.");
+                *m ^= *v;
+                *sum = sum.wrapping_add(u64::from(*v));
+            }
         }
 
-        let encrypted_base64_url = [&bytes[..base_index], &bytes[(base_index + 1)..]].concat();
+        for k in 0..lanes {
+            let path = self.swap_path(bases[k], m[k], sum[k], len);
+
+            for i in 0..len {
+                let p = path[i % 8];
 
-        let encrypted = base64_url::decode(&encrypted_base64_url)
-            .map_err(|_| "The URL component is incorrect.")?;
+                if i == p {
+                    continue;
+                }
 
-        self.decrypt(&(base, encrypted))
+                data.swap(i * lanes + k, p * lanes + k);
+            }
+        }
+
+        Ok(())
     }
 
-    pub fn decrypt_url_component_and_push_to_vec<S: AsRef<str>>(
+    /// Decrypt `bases.len()` ciphers whose **bodies** have the same length at once, inverting `encrypt_columns_in_place`. The **bodies** are stored column by column, so the byte `j` of the **body** `k` is `data[j * bases.len() + k]`, and they are replaced with the plaintexts in the same layout. Nothing is changed if an error is returned.
+    pub fn decrypt_columns_in_place(
         &self,
-        url_component: S,
-        mut output: Vec<u8>,
-    ) -> Result<Vec<u8>, &'static str> {
-        let bytes = url_component.as_ref().as_bytes();
-        let len = bytes.len();
+        data: &mut [u8],
+        bases: &[u8],
+    ) -> Result<(), ShortCryptError> {
+        let len = columns_len(data.len(), bases.len())?;
 
-        if len < 1 {
-            return Err("The URL component is incorrect.");
+        if bases.iter().any(|base| *base > 31) {
+            return Err(ShortCryptError::InvalidBase);
         }
 
-        let base_index = {
-            let mut sum = 0u64;
-
-            for n in bytes.iter().copied() {
-                sum = sum.wrapping_add(u64::from(n));
+        if len == 0 {
+            if self.checksum && bases.iter().any(|base| *base != 0xFF % 32) {
+                return Err(ShortCryptError::InvalidChecksum);
             }
 
-            ((self.key_sum_rev ^ sum) % (len as u64)) as usize
-        };
+            return Ok(());
+        }
 
-        let base = string_64_to_u8!(bytes[base_index]);
+        let lanes = bases.len();
 
-        if base > 31 {
-            return Err("The URL component is incorrect.");
-        }
+        let mut m = vec![0u8; lanes];
+        let mut sum = vec![0u64; lanes];
 
-        let encrypted_base64_url = [&bytes[..base_index], &bytes[(base_index + 1)..]].concat();
+        for row in data.chunks_exact(lanes) {
+            for ((v, m), sum) in row.iter().zip(m.iter_mut()).zip(sum.iter_mut()) {
+                *m ^= *v;
+                *sum = sum.wrapping_add(u64::from(*v));
+            }
+        }
 
-        let encrypted = base64_url::decode(&encrypted_base64_url)
-            .map_err(|_| "The URL component is incorrect.")?;
+        for k in 0..lanes {
+            let path = self.swap_path(bases[k], m[k], sum[k], len);
 
-        let len = encrypted.len();
+            for i in (0..len).rev() {
+                let p = path[i % 8];
 
-        output.reserve(len);
+                if i == p {
+                    continue;
+                }
 
-        self.decrypt_inner(base, &encrypted, &mut output);
+                data.swap(i * lanes + k, p * lanes + k);
+            }
+        }
 
-        Ok(output)
-    }
+        if self.checksum {
+            let mut crc = vec![0xFFu8; lanes];
 
-    pub fn encrypt_to_qr_code_alphanumeric<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
-        let (base, encrypted) = self.encrypt(data);
+            for (j, row) in data.chunks_exact(lanes).enumerate() {
+                let key = self.hashed_key[j % 8];
 
-        let base = u8_to_string_32!(base);
+                for ((crc, v), base) in crc.iter_mut().zip(row.iter()).zip(bases.iter()) {
+                    *crc = crc::CRC8_CDMA2000_TABLE[(*crc ^ *v ^ key ^ base) as usize];
+                }
+            }
 
-        let base_char = base as char;
+            if crc.iter().zip(bases.iter()).any(|(crc, base)| crc % 32 != *base) {
+                // undo the permutation so that the bodies are left as they were
+                for k in 0..lanes {
+                    let path = self.swap_path(bases[k], m[k], sum[k], len);
 
-        let mut result = String::with_capacity(1 + ((encrypted.len() * 8 + 4) / 5));
+                    for i in 0..len {
+                        let p = path[i % 8];
 
-        result.push_str(&base32::encode(
-            base32::Alphabet::RFC4648 {
-                padding: false
-            },
-            &encrypted,
-        ));
+                        if i == p {
+                            continue;
+                        }
 
-        let mut sum = u64::from(base);
+                        data.swap(i * lanes + k, p * lanes + k);
+                    }
+                }
 
-        for n in result.bytes() {
-            sum = sum.wrapping_add(u64::from(n));
+                return Err(ShortCryptError::InvalidChecksum);
+            }
         }
 
-        let base_index = ((self.key_sum_rev ^ sum) % ((result.len() + 1) as u64)) as usize;
+        for (j, row) in data.chunks_exact_mut(lanes).enumerate() {
+            let key = self.hashed_key[j % 8];
 
-        result.insert(base_index, base_char);
+            for (v, base) in row.iter_mut().zip(bases.iter()) {
+                *v ^= key ^ base;
+            }
+        }
 
-        result
+        Ok(())
     }
 
-    pub fn encrypt_to_qr_code_alphanumeric_and_push_to_string<
-        T: ?Sized + AsRef<[u8]>,
-        S: Into<String>,
-    >(
+    /// Same as `encrypt`, but the **body** is written into `scratch`, whose buffer is reused across calls instead of allocating a `Vec` for every cipher.
+    #[inline]
+    pub fn encrypt_with_scratch<'s, T: ?Sized + AsRef<[u8]>>(
         &self,
         data: &T,
-        output: S,
-    ) -> String {
-        let (base, encrypted) = self.encrypt(data);
+        scratch: &'s mut EncryptScratch,
+    ) -> CipherRef<'s> {
+        let body = &mut scratch.body;
 
-        let base = u8_to_string_32!(base);
+        body.clear();
+        body.extend_from_slice(data.as_ref());
 
-        let base_char = base as char;
+        let base = self.encrypt_in_place(body);
 
-        let mut output = output.into();
+        CipherRef(base, body)
+    }
 
-        let original_len = output.len();
+    /// Same as `encrypt_to_url_component`, but the text is written into `scratch`, whose buffers are reused across calls.
+    #[inline]
+    pub fn encrypt_to_url_component_with_scratch<'s, T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        scratch: &'s mut EncryptScratch,
+    ) -> &'s str {
+        scratch.text.clear();
+
+        self.push_bits_with_scratch(
+            data.as_ref(),
+            &mut scratch.body,
+            URL_COMPONENT_ALPHABET,
+            6,
+            &mut scratch.text,
+        );
+
+        &scratch.text
+    }
 
-        output.push_str(&base32::encode(
-            base32::Alphabet::RFC4648 {
-                padding: false
-            },
-            &encrypted,
-        ));
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the text is written into `scratch`, whose buffers are reused across calls.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_with_scratch<'s, T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        scratch: &'s mut EncryptScratch,
+    ) -> &'s str {
+        scratch.text.clear();
+
+        self.push_bits_with_scratch(
+            data.as_ref(),
+            &mut scratch.body,
+            BASE32_ALPHABET,
+            5,
+            &mut scratch.text,
+        );
+
+        &scratch.text
+    }
 
-        let mut sum = u64::from(base);
+    /// Same as `decrypt`, but the plaintext is written into `scratch`, whose buffer is reused across calls instead of allocating a `Vec` for every plaintext.
+    #[inline]
+    pub fn decrypt_with_scratch<'s, 'a, C: Into<CipherRef<'a>>>(
+        &self,
+        data: C,
+        scratch: &'s mut DecryptScratch,
+    ) -> Result<&'s [u8], ShortCryptError> {
+        let data = data.into();
 
-        for n in output.bytes().skip(original_len) {
-            sum = sum.wrapping_add(u64::from(n));
+        if data.base() > 31 {
+            return Err(ShortCryptError::InvalidBase);
         }
 
-        let base_index =
-            ((self.key_sum_rev ^ sum) % ((output.len() - original_len + 1) as u64)) as usize;
+        scratch.plaintext.clear();
 
-        output.insert(original_len + base_index, base_char);
+        self.decrypt_inner(data.base(), data.body(), &mut scratch.plaintext)?;
 
-        output
+        Ok(&scratch.plaintext)
     }
 
-    pub fn decrypt_qr_code_alphanumeric<S: AsRef<str>>(
+    /// Same as `decrypt_url_component`, but the plaintext is written into `scratch`, whose buffer is reused across calls.
+    #[inline]
+    pub fn decrypt_url_component_with_scratch<'s, S: AsRef<str>>(
+        &self,
+        url_component: S,
+        scratch: &'s mut DecryptScratch,
+    ) -> Result<&'s [u8], ShortCryptError> {
+        scratch.plaintext.clear();
+
+        self.decrypt_url_component_append(url_component, &mut scratch.plaintext)?;
+
+        Ok(&scratch.plaintext)
+    }
+
+    /// Same as `decrypt_qr_code_alphanumeric`, but the plaintext is written into `scratch`, whose buffer is reused across calls.
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_with_scratch<'s, S: AsRef<str>>(
         &self,
         qr_code_alphanumeric: S,
-    ) -> Result<Vec<u8>, &'static str> {
-        let bytes = qr_code_alphanumeric.as_ref().as_bytes();
-        let len = bytes.len();
+        scratch: &'s mut DecryptScratch,
+    ) -> Result<&'s [u8], ShortCryptError> {
+        scratch.plaintext.clear();
 
-        if len < 1 {
-            return Err("The QR code alphanumeric text is incorrect.");
-        }
+        self.decrypt_qr_code_alphanumeric_append(qr_code_alphanumeric, &mut scratch.plaintext)?;
 
-        let base_index = {
-            let mut sum = 0u64;
+        Ok(&scratch.plaintext)
+    }
 
-            for n in bytes.iter().copied() {
-                sum = sum.wrapping_add(u64::from(n));
+    /// Encrypt all the data from `reader` into `writer` frame by frame, so a payload of many megabytes only needs the memory of one frame. Every frame holds `LARGE_FRAME_LEN` bytes of the plaintext and is encrypted on its own like `encrypt`, then written as its **base** (one byte) followed by its **body**. The last frame is shorter (possibly empty), is marked in the highest bit of its **base** byte, and has the length of its **body** as two bytes after the **base**. Returns the number of bytes written.
+    ///
+    /// The permutation only covers one frame, and frames are not bound to their positions, so identical frames produce identical ciphers, and frames before the last one can be reordered or dropped without being noticed by `decrypt_large`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn encrypt_large<R: ?Sized + std::io::Read, W: ?Sized + std::io::Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> std::io::Result<u64> {
+        large::encrypt(self, reader, writer)
+    }
+
+    /// Decrypt the frames created by `encrypt_large` from `reader` into `writer`, one frame at a time. Returns the number of bytes of the plaintext written. An incorrect frame, a stream which ends before the last frame, or data after the last frame is reported as an error of the kind `InvalidData` which wraps a `ShortCryptError`. The plaintexts of the frames before it have already been written.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn decrypt_large<R: ?Sized + std::io::Read, W: ?Sized + std::io::Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> std::io::Result<u64> {
+        large::decrypt(self, reader, writer)
+    }
+
+    /// Create an `Encryptor` to encrypt data which arrives in chunks.
+    #[inline]
+    pub fn encryptor(&self) -> Encryptor<'_> {
+        Encryptor::new(self)
+    }
+
+    /// Create a `Decryptor` to decrypt a cipher or a text which arrives in chunks.
+    #[inline]
+    pub fn decryptor(&self) -> Decryptor<'_> {
+        Decryptor::new(self)
+    }
+
+    /// Encrypt `data` in place, so the buffer becomes the **body** of the cipher. Returns the **base**.
+    pub fn encrypt_in_place(&self, data: &mut [u8]) -> u8 {
+        let base = compute_base(data);
+
+        self.encrypt_in_place_with_base(data, base);
+
+        base
+    }
+
+    /// Encrypt `data` in place with a **base** derived from the CRC-8 of the plaintext.
+    pub(crate) fn encrypt_in_place_with_base(&self, data: &mut [u8], base: u8) {
+        keystream::xor_key(data, self.hashed_key.map(|k| k ^ base));
+
+        let (m, sum) = keystream::fold(data);
+
+        self.permute(data, base, m, sum);
+    }
+
+    /// Encrypt `data` into `out`, which has the same length. The copy, the keystream XOR and the folding are fused into one pass over `data`. Returns the **base**.
+    fn encrypt_to_slice(&self, data: &[u8], out: &mut [u8]) -> u8 {
+        let base = compute_base(data);
+
+        let (m, sum) = keystream::xor_key_fold_into(data, out, self.hashed_key.map(|k| k ^ base));
+
+        self.permute(out, base, m, sum);
+
+        base
+    }
+
+    /// Swap the bytes of a **body** after the keystream XOR. `m` and `sum` are the XOR and the wrapping sum of its bytes.
+    fn permute(&self, data: &mut [u8], base: u8, m: u8, sum: u64) {
+        let len = data.len();
+
+        let path = self.swap_path(base, m, sum, len);
+
+        for i in 0..len {
+            let p = path[i % 8];
+
+            if i == p {
+                continue;
             }
 
-            ((self.key_sum_rev ^ sum) % (len as u64)) as usize
+            data.swap(i, p);
+        }
+    }
+
+    /// Get the positions which the `i`-th byte of a **body** of `len` bytes is swapped with, indexed by `i % 8`. `m` and `sum` are the XOR and the wrapping sum of the bytes of the **body** after the keystream XOR, and the **base** is folded into them here. Since there are only 8 distinct positions, they are computed once instead of for every byte.
+    fn swap_path(&self, base: u8, m: u8, sum: u64, len: usize) -> [usize; 8] {
+        let m = m ^ base;
+        let sum: [u8; 8] = sum.wrapping_add(u64::from(base)).to_be_bytes();
+
+        let hashed_array: [u8; 8] = {
+            let mut hasher = CRCu64::crc64we();
+
+            hasher.digest(&[m]);
+            hasher.digest(&sum);
+
+            hasher.get_crc().to_be_bytes()
         };
 
-        let base = string_32_to_u8!(bytes[base_index]);
+        let mut path = [0; 8];
+
+        if len > 0 {
+            for (index, p) in path.iter_mut().enumerate() {
+                *p = (hashed_array[index] ^ self.hashed_key[index]) as usize % len;
+            }
+        }
+
+        path
+    }
+
+    /// Decrypt a `Cipher` (passed by reference) or a `CipherRef`.
+    #[inline]
+    pub fn decrypt<'a, C: Into<CipherRef<'a>>>(&self, data: C) -> Result<Vec<u8>, ShortCryptError> {
+        let data = data.into();
+
+        self.decrypt_slice(data.base(), data.body())
+    }
 
+    /// Decrypt a cipher given as its **base** and a borrowed **body**, e.g. a field of a decoded message, without building a `Cipher` first.
+    #[inline]
+    pub fn decrypt_slice(&self, base: u8, data: &[u8]) -> Result<Vec<u8>, ShortCryptError> {
         if base > 31 {
-            return Err("The QR code alphanumeric text is incorrect.");
+            return Err(ShortCryptError::InvalidBase);
         }
 
-        let encrypted_base32 =
-            String::from_utf8([&bytes[..base_index], &bytes[(base_index + 1)..]].concat())
-                .map_err(|_| "The QR code alphanumeric text is incorrect.")?;
+        let len = data.len();
 
-        let encrypted = match base32::decode(
-            base32::Alphabet::RFC4648 {
-                padding: false
-            },
-            &encrypted_base32,
-        ) {
-            Some(t) => t,
-            None => return Err("The QR code alphanumeric text is incorrect."),
-        };
+        let mut decrypted = Vec::with_capacity(len);
+
+        self.decrypt_inner(base, data, &mut decrypted)?;
 
-        self.decrypt(&(base, encrypted))
+        Ok(decrypted)
     }
 
-    pub fn decrypt_qr_code_alphanumeric_and_push_to_vec<S: AsRef<str>>(
+    /// Decrypt a cipher given as its **base** and **body**, and write the plaintext into the beginning of `out`, without any heap allocation. Returns the length of the plaintext, which is equal to the length of `body`.
+    pub fn decrypt_into(
         &self,
-        qr_code_alphanumeric: S,
-        mut output: Vec<u8>,
-    ) -> Result<Vec<u8>, &'static str> {
-        let bytes = qr_code_alphanumeric.as_ref().as_bytes();
-        let len = bytes.len();
+        base: u8,
+        body: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize, ShortCryptError> {
+        if base > 31 {
+            return Err(ShortCryptError::InvalidBase);
+        }
 
-        if len < 1 {
-            return Err("The QR code alphanumeric text is incorrect.");
+        let len = body.len();
+
+        let out = out.get_mut(..len).ok_or(ShortCryptError::BufferTooSmall)?;
+
+        out.copy_from_slice(body);
+
+        self.decrypt_in_slice(base, out)?;
+
+        Ok(len)
+    }
+
+    /// Decrypt a fixed-size record created by `encrypt_array`, without touching the heap.
+    #[inline]
+    pub fn decrypt_array<const N: usize>(
+        &self,
+        base: u8,
+        body: &[u8; N],
+    ) -> Result<[u8; N], ShortCryptError> {
+        let mut plaintext = *body;
+
+        self.decrypt_in_place(base, &mut plaintext)?;
+
+        Ok(plaintext)
+    }
+
+    /// Decrypt a cipher given as its **base** and an owned **body**. The allocation of `body` is reused for the plaintext.
+    #[inline]
+    pub fn decrypt_vec(&self, base: u8, mut body: Vec<u8>) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_in_place(base, &mut body)?;
+
+        Ok(body)
+    }
+
+    /// Decrypt the **body** of a cipher in place, inverting `encrypt_in_place`.
+    #[inline]
+    pub fn decrypt_in_place(&self, base: u8, data: &mut [u8]) -> Result<(), ShortCryptError> {
+        if base > 31 {
+            return Err(ShortCryptError::InvalidBase);
         }
 
-        let base_index = {
-            let mut sum = 0u64;
+        self.decrypt_in_slice(base, data)
+    }
 
-            for n in bytes.iter().copied() {
-                sum = sum.wrapping_add(u64::from(n));
+    /// Decrypt the **body** of a cipher in place.
+    fn decrypt_in_slice(&self, base: u8, data: &mut [u8]) -> Result<(), ShortCryptError> {
+        let (m, sum) = keystream::fold(data);
+
+        self.decrypt_folded(data, base, m, sum)
+    }
+
+    /// Decrypt the **body** of a cipher in place. `m` and `sum` are the XOR and the wrapping sum of its bytes, which have been folded while it was decoded. With the checksum option, the plaintext must match the base.
+    pub(crate) fn decrypt_folded(
+        &self,
+        data: &mut [u8],
+        base: u8,
+        m: u8,
+        sum: u64,
+    ) -> Result<(), ShortCryptError> {
+        let len = data.len();
+
+        let path = self.swap_path(base, m, sum, len);
+
+        for i in (0..len).rev() {
+            let p = path[i % 8];
+
+            if i == p {
+                continue;
             }
 
-            ((self.key_sum_rev ^ sum) % (len as u64)) as usize
+            data.swap(i, p);
+        }
+
+        keystream::xor_key(data, self.hashed_key.map(|k| k ^ base));
+
+        if self.checksum && compute_base(data) != base {
+            return Err(ShortCryptError::InvalidChecksum);
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt the **body** of a cipher and append the plaintext to `output`. The **body** is copied into `output` once and decrypted there, so no temporary buffer is allocated. `output` is not changed if the decryption fails.
+    fn decrypt_inner(
+        &self,
+        base: u8,
+        data: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), ShortCryptError> {
+        let start = output.len();
+
+        output.extend_from_slice(data);
+
+        self.decrypt_in_slice(base, &mut output[start..]).inspect_err(|_| output.truncate(start))
+    }
+
+    /// A 122-bit mask derived from the key, which hides the zero padding bits of `encrypt_to_uuid`.
+    #[inline]
+    fn uuid_mask(&self) -> u128 {
+        let hashed_key = u64::from_be_bytes(self.hashed_key);
+
+        ((u128::from(hashed_key) << 64) | u128::from(hashed_key.swap_bytes() ^ self.key_sum_rev))
+            & UUID_PAYLOAD_MASK
+    }
+
+    /// Insert the symbols of a base into `output[start..]` at a position derived from the key and the whole encoded text.
+    fn insert_base(&self, output: &mut String, start: usize, base: &str) {
+        let mut sum = 0u64;
+
+        for n in base.bytes().chain(output.bytes().skip(start)) {
+            sum = sum.wrapping_add(u64::from(n));
+        }
+
+        let base_index = self.base_index(sum, output.len() - start + 1);
+
+        output.insert_str(start + base_index, base);
+    }
+
+    /// Remove separators which are not symbols (as judged by `is_symbol`) from a text, if separators are set to be ignored.
+    fn strip_separators<'a, F: Fn(u8) -> bool>(
+        &self,
+        bytes: &'a [u8],
+        is_symbol: F,
+    ) -> Cow<'a, [u8]> {
+        let is_removable = |n: u8| is_separator(n) && !is_symbol(n);
+
+        if self.ignore_separators && bytes.iter().copied().any(is_removable) {
+            Cow::Owned(bytes.iter().copied().filter(|n| !is_removable(*n)).collect())
+        } else {
+            Cow::Borrowed(bytes)
+        }
+    }
+
+    /// Return `ShortCryptError::InvalidCharacter` with the position in the original text (before separators are removed) if `bytes` contains a character which is neither a symbol nor an ignored separator.
+    fn check_characters<F: Fn(u8) -> bool>(
+        &self,
+        bytes: &[u8],
+        is_symbol: F,
+    ) -> Result<(), ShortCryptError> {
+        match bytes
+            .iter()
+            .position(|n| !(is_symbol(*n) || (self.ignore_separators && is_separator(*n))))
+        {
+            Some(index) => Err(ShortCryptError::InvalidCharacter {
+                index,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the index of the base symbols among `positions` possible positions of an encrypted text, whose symbols add up to `sum`.
+    #[inline]
+    pub(crate) fn base_index(&self, sum: u64, positions: usize) -> usize {
+        match self.base_placement {
+            BasePlacement::Keyed => ((self.key_sum_rev ^ sum) % (positions as u64)) as usize,
+            BasePlacement::Start => 0,
+            BasePlacement::End => positions - 1,
+        }
+    }
+
+    /// Find the index of the base symbols (whose total length is `width`) inside an encoded text. The length of the text must not be smaller than `width`.
+    fn find_base(&self, bytes: &[u8], width: usize) -> usize {
+        let mut sum = 0u64;
+
+        for n in bytes.iter().copied() {
+            sum = sum.wrapping_add(u64::from(n));
+        }
+
+        self.base_index(sum, bytes.len() - width + 1)
+    }
+
+    /// Encode a cipher with `encoding` and push the text to `output`.
+    fn encrypt_with_encoding<E: ?Sized + OutputEncoding>(
+        &self,
+        data: &[u8],
+        encoding: &E,
+        output: &mut String,
+    ) {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        self.encode_cipher(base, &encrypted, encoding, output);
+    }
+
+    /// Encode the **body** of a cipher with `encoding`, insert the base symbol, and push the text to `output`.
+    pub(crate) fn encode_cipher<E: ?Sized + OutputEncoding>(
+        &self,
+        base: u8,
+        body: &[u8],
+        encoding: &E,
+        output: &mut String,
+    ) {
+        let base_char = encoding.base_to_symbol(base) as char;
+
+        let original_len = output.len();
+
+        encoding.encode(body, output);
+
+        self.insert_base(output, original_len, base_char.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Take out the base symbol inserted by `encrypt_with_encoding` and decode the rest of the text with `encoding`. Returns the base and the **body**, or `error` if the **body** cannot be decoded.
+    pub(crate) fn decode_with_encoding<E: ?Sized + OutputEncoding>(
+        &self,
+        text: &[u8],
+        encoding: &E,
+        error: ShortCryptError,
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        let alphabet = encoding.alphabet();
+
+        self.check_characters(text, |n| alphabet.contains(&n))?;
+
+        let text = self.strip_separators(text, |n| alphabet.contains(&n));
+        let bytes: &[u8] = &text;
+
+        if bytes.is_empty() {
+            return Err(ShortCryptError::InvalidLength);
+        }
+
+        let base_index = self.find_base(bytes, 1);
+
+        let base = match encoding.symbol_to_base(bytes[base_index]) {
+            Some(base) if base <= 31 => base,
+            _ => return Err(ShortCryptError::InvalidBase),
         };
 
-        let base = string_32_to_u8!(bytes[base_index]);
+        let encrypted_text = [&bytes[..base_index], &bytes[(base_index + 1)..]].concat();
 
-        if base > 31 {
-            return Err("The QR code alphanumeric text is incorrect.");
+        let encrypted = encoding.decode(&encrypted_text).ok_or(error)?;
+
+        Ok((base, encrypted))
+    }
+
+    /// Take out the base symbol of a text created by `encrypt_to_url_component` and decode the rest. Returns the base and the **body**.
+    #[inline]
+    pub(crate) fn decode_url_component(
+        &self,
+        text: &[u8],
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        let mut encrypted = Vec::new();
+
+        let (base, ..) = self.decode_url_component_into(text, &mut encrypted)?;
+
+        Ok((base, encrypted))
+    }
+
+    /// Same as `decode_url_component`, but the **body** is appended to `output`, which is not changed if the decoding fails. Returns the base, and the XOR and the wrapping sum of the bytes of the **body**.
+    #[inline]
+    pub(crate) fn decode_url_component_into(
+        &self,
+        text: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(u8, u8, u64), ShortCryptError> {
+        self.decode_bits(text, &UrlComponentEncoding, output)
+    }
+
+    /// Take out the base symbol of a text created by `encrypt_to_qr_code_alphanumeric` and decode the rest. Returns the base and the **body**.
+    #[inline]
+    pub(crate) fn decode_qr_code_alphanumeric(
+        &self,
+        text: &[u8],
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        let mut encrypted = Vec::new();
+
+        let (base, ..) = self.decode_qr_code_alphanumeric_into(text, &mut encrypted)?;
+
+        Ok((base, encrypted))
+    }
+
+    /// Same as `decode_qr_code_alphanumeric`, but the **body** is appended to `output`, which is not changed if the decoding fails. Returns the base, and the XOR and the wrapping sum of the bytes of the **body**.
+    #[inline]
+    pub(crate) fn decode_qr_code_alphanumeric_into(
+        &self,
+        text: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(u8, u8, u64), ShortCryptError> {
+        self.decode_bits(text, &QrCodeAlphanumericEncoding, output)
+    }
+
+    /// Same as `decode_with_encoding` for a `BitsEncoding`, but the symbols around the base are decoded directly into `output` instead of being copied into a new text first. The decoded bytes are folded on the way, so the decryption does not need to walk them again. Returns the base, and the XOR and the wrapping sum of the bytes of the **body**.
+    fn decode_bits<E: BitsEncoding>(
+        &self,
+        text: &[u8],
+        encoding: &E,
+        output: &mut Vec<u8>,
+    ) -> Result<(u8, u8, u64), ShortCryptError> {
+        let (decoding_table, bits, error) = (E::DECODING_TABLE, E::BITS, E::ERROR);
+
+        let alphabet = encoding.alphabet();
+
+        self.check_characters(text, |n| alphabet.contains(&n))?;
+
+        let text = self.strip_separators(text, |n| alphabet.contains(&n));
+        let bytes: &[u8] = &text;
+
+        if bytes.is_empty() {
+            return Err(ShortCryptError::InvalidLength);
         }
 
-        let encrypted_base32 =
-            String::from_utf8([&bytes[..base_index], &bytes[(base_index + 1)..]].concat())
-                .map_err(|_| "The QR code alphanumeric text is incorrect.")?;
+        let base_index = self.find_base(bytes, 1);
 
-        let encrypted = match base32::decode(
-            base32::Alphabet::RFC4648 {
-                padding: false
-            },
-            &encrypted_base32,
-        ) {
-            Some(t) => t,
-            None => return Err("The QR code alphanumeric text is incorrect."),
+        let base = match encoding.symbol_to_base(bytes[base_index]) {
+            Some(base) if base <= 31 => base,
+            _ => return Err(ShortCryptError::InvalidBase),
         };
 
-        let len = encrypted.len();
+        let (front, back) = (&bytes[..base_index], &bytes[(base_index + 1)..]);
 
-        output.reserve(len);
+        let len = front.len() + back.len();
 
-        self.decrypt_inner(base, &encrypted, &mut output);
+        // the base symbols may not be a part of the alphabet of the body
+        if !bits::is_valid_body(
+            front.iter().chain(back).map(|n| decoding_table[*n as usize]),
+            bits,
+            E::STRICT,
+        ) {
+            return Err(error);
+        }
 
-        Ok(output)
+        output.reserve(len * bits as usize / 8);
+
+        let mut m = 0;
+        let mut sum = 0u64;
+
+        bits::decode_iter(
+            front.iter().chain(back).map(|n| decoding_table[*n as usize]),
+            bits,
+            |n| {
+                m ^= n;
+                sum = sum.wrapping_add(u64::from(n));
+
+                output.push(n);
+            },
+        );
+
+        Ok((base, m, sum))
+    }
+
+    /// Encrypt data into a text with a user-defined `OutputEncoding`.
+    #[inline]
+    pub fn encrypt_to<E: ?Sized + OutputEncoding, T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        encoding: &E,
+    ) -> String {
+        let mut result = String::new();
+
+        self.encrypt_with_encoding(data.as_ref(), encoding, &mut result);
+
+        result
+    }
+
+    /// Decrypt a text created by `encrypt_to` with the same `OutputEncoding`.
+    #[inline]
+    pub fn decrypt_from<E: ?Sized + OutputEncoding, S: AsRef<str>>(
+        &self,
+        text: S,
+        encoding: &E,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, encrypted) = self.decode_with_encoding(
+            text.as_ref().as_bytes(),
+            encoding,
+            ShortCryptError::InvalidText,
+        )?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// The exact length of the text created by `encrypt_to_url_component` for a plaintext of `plaintext_len` bytes. It does not depend on the key or the plaintext itself.
+    #[inline]
+    pub const fn url_component_len(plaintext_len: usize) -> usize {
+        // 3 bytes become 4 characters, and the remaining 1 or 2 bytes become 2 or 3 characters
+        1 + plaintext_len / 3 * 4 + [0, 2, 3][plaintext_len % 3]
+    }
+
+    /// The exact length of the text created by `encrypt_to_qr_code_alphanumeric` for a plaintext of `plaintext_len` bytes. It does not depend on the key or the plaintext itself.
+    #[inline]
+    pub const fn qr_code_alphanumeric_len(plaintext_len: usize) -> usize {
+        // 5 bytes become 8 characters, and the remaining 1 to 4 bytes become 2, 4, 5 or 7 characters
+        1 + plaintext_len / 5 * 8 + [0, 2, 4, 5, 7][plaintext_len % 5]
     }
+
+    pub fn encrypt_to_url_component<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let mut result = String::new();
+
+        // a `String` can always grow
+        let _ = self.encrypt_to_url_component_into(data, &mut result);
+
+        result
+    }
+
+    pub fn encrypt_to_url_component_and_push_to_string<T: ?Sized + AsRef<[u8]>, S: Into<String>>(
+        &self,
+        data: &T,
+        output: S,
+    ) -> String {
+        let mut output = output.into();
+
+        self.encrypt_to_url_component_append(data, &mut output);
+
+        output
+    }
+
+    /// Same as `encrypt_to_url_component_and_push_to_string`, but the ASCII text is pushed to a byte vector, such as the body of an HTTP response.
+    pub fn encrypt_to_url_component_and_push_to_vec<T: ?Sized + AsRef<[u8]>, V: Into<Vec<u8>>>(
+        &self,
+        data: &T,
+        output: V,
+    ) -> Vec<u8> {
+        let mut output = output.into();
+
+        // a `Vec<u8>` can always grow
+        let _ = self.encrypt_to_url_component_into(data, &mut output);
+
+        output
+    }
+
+    /// Same as `encrypt_to_url_component_and_push_to_string`, but the text is appended to `output` in place.
+    #[inline]
+    pub fn encrypt_to_url_component_append<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        output: &mut String,
+    ) {
+        // a `String` can always grow
+        let _ = self.encrypt_to_url_component_into(data, output);
+    }
+
+    /// Encrypt `data` and push the text of `encrypt_to_url_component` to any `OutputBuffer`, such as a `String`, a `Vec<u8>` or a fixed-capacity string. If the buffer cannot hold the whole text, it is not changed and `ShortCryptError::BufferTooSmall` is returned.
+    #[inline]
+    pub fn encrypt_to_url_component_into<T: ?Sized + AsRef<[u8]>, B: ?Sized + OutputBuffer>(
+        &self,
+        data: &T,
+        output: &mut B,
+    ) -> Result<(), ShortCryptError> {
+        self.encrypt_to_output(data.as_ref(), URL_COMPONENT_ALPHABET, 6, output)
+    }
+
+    pub fn decrypt_url_component<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = Vec::new();
+
+        self.decrypt_url_component_append(url_component, &mut plaintext)?;
+
+        Ok(plaintext)
+    }
+
+    pub fn decrypt_url_component_and_push_to_vec<S: AsRef<str>>(
+        &self,
+        url_component: S,
+        mut output: Vec<u8>,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_url_component_append(url_component, &mut output)?;
+
+        Ok(output)
+    }
+
+    /// Same as `decrypt_url_component_and_push_to_vec`, but the plaintext is appended to `output` in place. `output` is not changed if the decryption fails.
+    pub fn decrypt_url_component_append<S: AsRef<str>>(
+        &self,
+        url_component: S,
+        output: &mut Vec<u8>,
+    ) -> Result<(), ShortCryptError> {
+        let start = output.len();
+
+        let (base, m, sum) =
+            self.decode_url_component_into(url_component.as_ref().as_bytes(), output)?;
+
+        self.decrypt_folded(&mut output[start..], base, m, sum)
+            .inspect_err(|_| output.truncate(start))
+    }
+
+    pub fn encrypt_to_qr_code_alphanumeric<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let mut result = String::new();
+
+        // a `String` can always grow
+        let _ = self.encrypt_to_qr_code_alphanumeric_into(data, &mut result);
+
+        result
+    }
+
+    pub fn encrypt_to_qr_code_alphanumeric_and_push_to_string<
+        T: ?Sized + AsRef<[u8]>,
+        S: Into<String>,
+    >(
+        &self,
+        data: &T,
+        output: S,
+    ) -> String {
+        let mut output = output.into();
+
+        self.encrypt_to_qr_code_alphanumeric_append(data, &mut output);
+
+        output
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric_and_push_to_string`, but the ASCII text is pushed to a byte vector, such as the body of an HTTP response.
+    pub fn encrypt_to_qr_code_alphanumeric_and_push_to_vec<
+        T: ?Sized + AsRef<[u8]>,
+        V: Into<Vec<u8>>,
+    >(
+        &self,
+        data: &T,
+        output: V,
+    ) -> Vec<u8> {
+        let mut output = output.into();
+
+        // a `Vec<u8>` can always grow
+        let _ = self.encrypt_to_qr_code_alphanumeric_into(data, &mut output);
+
+        output
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric_and_push_to_string`, but the text is appended to `output` in place.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_append<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        output: &mut String,
+    ) {
+        // a `String` can always grow
+        let _ = self.encrypt_to_qr_code_alphanumeric_into(data, output);
+    }
+
+    /// Encrypt `data` and push the text of `encrypt_to_qr_code_alphanumeric` to any `OutputBuffer`, such as a `String`, a `Vec<u8>` or a fixed-capacity string. If the buffer cannot hold the whole text, it is not changed and `ShortCryptError::BufferTooSmall` is returned.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_into<
+        T: ?Sized + AsRef<[u8]>,
+        B: ?Sized + OutputBuffer,
+    >(
+        &self,
+        data: &T,
+        output: &mut B,
+    ) -> Result<(), ShortCryptError> {
+        self.encrypt_to_output(data.as_ref(), BASE32_ALPHABET, 5, output)
+    }
+
+    pub fn decrypt_qr_code_alphanumeric<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = Vec::new();
+
+        self.decrypt_qr_code_alphanumeric_append(qr_code_alphanumeric, &mut plaintext)?;
+
+        Ok(plaintext)
+    }
+
+    pub fn decrypt_qr_code_alphanumeric_and_push_to_vec<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+        mut output: Vec<u8>,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_qr_code_alphanumeric_append(qr_code_alphanumeric, &mut output)?;
+
+        Ok(output)
+    }
+
+    /// Same as `decrypt_qr_code_alphanumeric_and_push_to_vec`, but the plaintext is appended to `output` in place. `output` is not changed if the decryption fails.
+    pub fn decrypt_qr_code_alphanumeric_append<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+        output: &mut Vec<u8>,
+    ) -> Result<(), ShortCryptError> {
+        let start = output.len();
+
+        let (base, m, sum) = self
+            .decode_qr_code_alphanumeric_into(qr_code_alphanumeric.as_ref().as_bytes(), output)?;
+
+        self.decrypt_folded(&mut output[start..], base, m, sum)
+            .inspect_err(|_| output.truncate(start))
+    }
+
+    /// Insert the base into `output[start..]` with symbols in `alphabet`. If the size of the alphabet is smaller than 32, the base takes two symbols.
+    fn insert_base_symbols(&self, output: &mut String, start: usize, alphabet: &[u8], base: u8) {
+        let radix = alphabet.len();
+
+        if radix >= 32 {
+            let base_char = alphabet[base as usize] as char;
+
+            self.insert_base(output, start, base_char.encode_utf8(&mut [0; 4]));
+        } else {
+            let base = base as usize;
+
+            let base_chars = [alphabet[base / radix], alphabet[base % radix]];
+
+            self.insert_base(output, start, core::str::from_utf8(&base_chars).unwrap());
+        }
+    }
+
+    /// Map every byte of a text to its value in `decoding_table`, and take out the base symbols inserted by `insert_base_symbols`. Returns the base and the remaining values.
+    fn remove_base_symbols(
+        &self,
+        bytes: &[u8],
+        decoding_table: &[u8; 256],
+        radix: u32,
+    ) -> Result<(u8, Vec<u8>), ShortCryptError> {
+        let is_symbol = |n: u8| decoding_table[n as usize] != 0xFF;
+
+        self.check_characters(bytes, is_symbol)?;
+
+        let bytes = self.strip_separators(bytes, is_symbol);
+        let bytes: &[u8] = &bytes;
+
+        let base_width = if radix >= 32 { 1 } else { 2 };
+
+        if bytes.len() < base_width {
+            return Err(ShortCryptError::InvalidLength);
+        }
+
+        let mut digits: Vec<u8> = bytes.iter().map(|n| decoding_table[*n as usize]).collect();
+
+        let base_index = self.find_base(bytes, base_width);
+
+        let base = digits
+            .drain(base_index..(base_index + base_width))
+            .fold(0u32, |base, d| base * radix + u32::from(d));
+
+        if base > 31 {
+            return Err(ShortCryptError::InvalidBase);
+        }
+
+        Ok((base as u8, digits))
+    }
+
+    /// Encrypt `data` and encode the cipher with a big-number radix `alphabet`, then insert the base symbols.
+    fn encrypt_to_radix(&self, data: &[u8], alphabet: &[u8]) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut result = String::with_capacity(2 + encrypted.len() * 2);
+
+        radix::encode(&encrypted, alphabet, &mut result);
+
+        self.insert_base_symbols(&mut result, 0, alphabet, base);
+
+        result
+    }
+
+    /// Decrypt a text created by `encrypt_to_radix`.
+    fn decrypt_radix(
+        &self,
+        bytes: &[u8],
+        decoding_table: &[u8; 256],
+        radix: u32,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, digits) = self.remove_base_symbols(bytes, decoding_table, radix)?;
+
+        let encrypted = radix::decode(&digits, radix);
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// Encrypt `data` and encode the cipher with a bit-group `alphabet` (whose size is `2^bits`), then insert the base symbols.
+    fn encrypt_to_bits(&self, data: &[u8], alphabet: &[u8], bits: u32) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut result = String::with_capacity(2 + (encrypted.len() * 8).div_ceil(bits as usize));
+
+        bits::encode(&encrypted, alphabet, bits, &mut result);
+
+        self.insert_base_symbols(&mut result, 0, alphabet, base);
+
+        result
+    }
+
+    /// Decrypt a text created by `encrypt_to_bits`.
+    fn decrypt_bits(
+        &self,
+        bytes: &[u8],
+        decoding_table: &[u8; 256],
+        bits: u32,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, digits) = self.remove_base_symbols(bytes, decoding_table, 1 << bits)?;
+
+        let encrypted = bits::decode(&digits, bits).ok_or(ShortCryptError::InvalidLength)?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    pub fn encrypt_to_base58<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_radix(data.as_ref(), BASE58_ALPHABET)
+    }
+
+    pub fn decrypt_base58<S: AsRef<str>>(&self, base58: S) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_radix(base58.as_ref().as_bytes(), &BASE58_DECODING_TABLE, 58)
+    }
+
+    pub fn encrypt_to_base62<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_radix(data.as_ref(), BASE62_ALPHABET)
+    }
+
+    pub fn decrypt_base62<S: AsRef<str>>(&self, base62: S) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_radix(base62.as_ref().as_bytes(), &BASE62_DECODING_TABLE, 62)
+    }
+
+    pub fn encrypt_to_crockford32<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut result = String::with_capacity(1 + (encrypted.len() * 8).div_ceil(5));
+
+        bits::encode(&encrypted, CROCKFORD32_ALPHABET, 5, &mut result);
+
+        let base_char = CROCKFORD32_ALPHABET[base as usize] as char;
+
+        self.insert_base(&mut result, 0, base_char.encode_utf8(&mut [0; 4]));
+
+        result
+    }
+
+    /// The input is case-insensitive, and `O`, `I`, `L` are treated as `0`, `1`, `1`.
+    pub fn decrypt_crockford32<S: AsRef<str>>(
+        &self,
+        crockford32: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let crockford32 = self.strip_separators(crockford32.as_ref().as_bytes(), |n| {
+            CROCKFORD32_DECODING_TABLE[n as usize] != 0xFF
+        });
+        let bytes: &[u8] = &crockford32;
+        let len = bytes.len();
+
+        if len < 1 {
+            return Err(ShortCryptError::DecodeBase32);
+        }
+
+        // normalize the text first so that the base index does not depend on how it was typed
+        let mut normalized = Vec::with_capacity(len);
+
+        for n in bytes.iter().copied() {
+            let v = CROCKFORD32_DECODING_TABLE[n as usize];
+
+            if v == 0xFF {
+                return Err(ShortCryptError::DecodeBase32);
+            }
+
+            normalized.push(CROCKFORD32_ALPHABET[v as usize]);
+        }
+
+        let base_index = self.find_base(&normalized, 1);
+
+        let base = CROCKFORD32_DECODING_TABLE[normalized.remove(base_index) as usize];
+
+        let encrypted_crockford32 =
+            String::from_utf8(normalized).map_err(|_| ShortCryptError::DecodeBase32)?;
+
+        let encrypted = match base32::decode(base32::Alphabet::Crockford, &encrypted_crockford32) {
+            Some(t) => t,
+            None => return Err(ShortCryptError::DecodeBase32),
+        };
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// The base takes two hexadecimal digits.
+    pub fn encrypt_to_hex<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_bits(data.as_ref(), HEX_ALPHABET, 4)
+    }
+
+    pub fn decrypt_hex<S: AsRef<str>>(&self, hex: S) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_bits(hex.as_ref().as_bytes(), &HEX_DECODING_TABLE, 4)
+    }
+
+    /// The base takes two digits.
+    pub fn encrypt_to_qr_code_numeric<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_radix(data.as_ref(), DECIMAL_ALPHABET)
+    }
+
+    pub fn decrypt_qr_code_numeric<S: AsRef<str>>(
+        &self,
+        qr_code_numeric: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_radix(qr_code_numeric.as_ref().as_bytes(), &DECIMAL_DECODING_TABLE, 10)
+    }
+
+    pub fn encrypt_to_z_base_32<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_bits(data.as_ref(), Z_BASE_32_ALPHABET, 5)
+    }
+
+    pub fn decrypt_z_base_32<S: AsRef<str>>(
+        &self,
+        z_base_32: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_bits(z_base_32.as_ref().as_bytes(), &Z_BASE_32_DECODING_TABLE, 5)
+    }
+
+    /// The body is encoded by Ascii85 without the `z` abbreviation.
+    pub fn encrypt_to_base85<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut result = String::with_capacity(1 + (encrypted.len() * 5).div_ceil(4));
+
+        ascii85::encode(&encrypted, &mut result);
+
+        self.insert_base_symbols(&mut result, 0, &ascii85::ALPHABET, base);
+
+        result
+    }
+
+    pub fn decrypt_base85<S: AsRef<str>>(&self, base85: S) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, digits) =
+            self.remove_base_symbols(base85.as_ref().as_bytes(), &BASE85_DECODING_TABLE, 85)?;
+
+        let encrypted = ascii85::decode(&digits).ok_or(ShortCryptError::InvalidText)?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    pub fn encrypt_to_base45<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut result = String::with_capacity(1 + (encrypted.len() * 3).div_ceil(2));
+
+        base45::encode(&encrypted, &mut result);
+
+        self.insert_base_symbols(&mut result, 0, base45::ALPHABET, base);
+
+        result
+    }
+
+    pub fn decrypt_base45<S: AsRef<str>>(&self, base45: S) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, digits) =
+            self.remove_base_symbols(base45.as_ref().as_bytes(), &BASE45_DECODING_TABLE, 45)?;
+
+        let encrypted = base45::decode(&digits).ok_or(ShortCryptError::InvalidText)?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    pub fn encrypt_to_custom_alphabet<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        alphabet: &Alphabet,
+    ) -> String {
+        self.encrypt_to_bits(data.as_ref(), alphabet.as_bytes(), alphabet.bits())
+    }
+
+    pub fn decrypt_custom_alphabet<S: AsRef<str>>(
+        &self,
+        text: S,
+        alphabet: &Alphabet,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_bits(text.as_ref().as_bytes(), &alphabet.decoding_table, alphabet.bits())
+    }
+
+    /// Every byte of the body becomes one emoji in the range from U+1F400 to U+1F4FF.
+    pub fn encrypt_to_emoji<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let len = encrypted.len();
+
+        let mut sum = u64::from(EMOJI_START + u32::from(base));
+
+        for n in encrypted.iter().copied() {
+            sum = sum.wrapping_add(u64::from(EMOJI_START + u32::from(n)));
+        }
+
+        let base_index = self.base_index(sum, len + 1);
+
+        let mut result = String::with_capacity((len + 1) * 4);
+
+        for (i, n) in encrypted.iter().copied().enumerate() {
+            if i == base_index {
+                result.push(char::from_u32(EMOJI_START + u32::from(base)).unwrap());
+            }
+
+            result.push(char::from_u32(EMOJI_START + u32::from(n)).unwrap());
+        }
+
+        if base_index == len {
+            result.push(char::from_u32(EMOJI_START + u32::from(base)).unwrap());
+        }
+
+        result
+    }
+
+    /// The variation selector U+FE0F, which chat applications often add after an emoji to force the emoji presentation, is ignored.
+    pub fn decrypt_emoji<S: AsRef<str>>(&self, emoji: S) -> Result<Vec<u8>, ShortCryptError> {
+        let emoji = self.strip_separators(emoji.as_ref().as_bytes(), |_| false);
+        let emoji = core::str::from_utf8(&emoji).map_err(|_| ShortCryptError::InvalidText)?;
+
+        let mut encrypted = Vec::with_capacity(emoji.len() / 4);
+        let mut sum = 0u64;
+
+        for c in emoji.chars() {
+            // it carries no data
+            if c == '\u{FE0F}' {
+                continue;
+            }
+
+            let c = u32::from(c);
+
+            if !(EMOJI_START..(EMOJI_START + 256)).contains(&c) {
+                return Err(ShortCryptError::InvalidText);
+            }
+
+            encrypted.push((c - EMOJI_START) as u8);
+            sum = sum.wrapping_add(u64::from(c));
+        }
+
+        let len = encrypted.len();
+
+        if len < 1 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let base_index = self.base_index(sum, len);
+
+        let base = encrypted.remove(base_index);
+
+        if base > 31 {
+            return Err(ShortCryptError::InvalidBase);
+        }
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// Every 11 bits of the body become one word in the English word list of BIP-0039, and the words are joined with `-`. The base (along with the number of padding bits) also takes one word.
+    pub fn encrypt_to_words<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut indices: Vec<u16> = Vec::with_capacity(2 + encrypted.len() * 8 / 11);
+
+        let mut buffer = 0u32;
+        let mut buffer_bits = 0;
+
+        for n in encrypted.iter().copied() {
+            buffer = (buffer << 8) | u32::from(n);
+            buffer_bits += 8;
+
+            if buffer_bits >= 11 {
+                buffer_bits -= 11;
+
+                indices.push(((buffer >> buffer_bits) & 0x7FF) as u16);
+            }
+        }
+
+        let padding_bits = if buffer_bits > 0 {
+            indices.push(((buffer << (11 - buffer_bits)) & 0x7FF) as u16);
+
+            11 - buffer_bits
+        } else {
+            0
+        };
+
+        let base_word = u16::from(base) | (padding_bits << 5) as u16;
+
+        let mut sum = u64::from(base_word);
+
+        for i in indices.iter().copied() {
+            sum = sum.wrapping_add(u64::from(i));
+        }
+
+        let base_index = self.base_index(sum, indices.len() + 1);
+
+        indices.insert(base_index, base_word);
+
+        let mut result = String::with_capacity(indices.len() * 9);
+
+        for (i, index) in indices.iter().copied().enumerate() {
+            if i > 0 {
+                result.push('-');
+            }
+
+            result.push_str(words::WORDS[index as usize]);
+        }
+
+        result
+    }
+
+    /// The words can be separated by `-` or whitespaces, and are case-insensitive.
+    pub fn decrypt_words<S: AsRef<str>>(&self, words: S) -> Result<Vec<u8>, ShortCryptError> {
+        let mut indices: Vec<u16> = Vec::new();
+        let mut sum = 0u64;
+
+        for word in words.as_ref().split(|c: char| c == '-' || c.is_whitespace()) {
+            if word.is_empty() {
+                continue;
+            }
+
+            let index = words::WORDS
+                .binary_search_by(|w| w.bytes().cmp(word.bytes().map(|n| n.to_ascii_lowercase())))
+                .map_err(|_| ShortCryptError::InvalidText)?;
+
+            indices.push(index as u16);
+            sum = sum.wrapping_add(index as u64);
+        }
+
+        let len = indices.len();
+
+        if len < 1 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let base_index = self.base_index(sum, len);
+
+        let base_word = indices.remove(base_index);
+
+        let base = (base_word & 0x1F) as u8;
+        let padding_bits = (base_word >> 5) as usize;
+
+        let total_bits = indices.len() * 11;
+
+        if padding_bits > 10 || total_bits < padding_bits || (total_bits - padding_bits) % 8 != 0 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        // the padding bits of the last word must be zero, so every body has only one word sequence
+        if let Some(last) = indices.last() {
+            if last & ((1 << padding_bits) - 1) != 0 {
+                return Err(ShortCryptError::InvalidText);
+            }
+        }
+
+        let encrypted_len = (total_bits - padding_bits) / 8;
+
+        let mut encrypted = Vec::with_capacity(encrypted_len);
+
+        let mut buffer = 0u32;
+        let mut buffer_bits = 0;
+
+        for index in indices.iter().copied() {
+            buffer = (buffer << 11) | u32::from(index);
+            buffer_bits += 11;
+
+            while buffer_bits >= 8 && encrypted.len() < encrypted_len {
+                buffer_bits -= 8;
+
+                encrypted.push((buffer >> buffer_bits) as u8);
+            }
+        }
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// The body is encoded by the standard Base64 alphabet with `=` padding. The base is inserted before the padding.
+    pub fn encrypt_to_base64<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let base_char = BASE_SYMBOLS[base as usize] as char;
+
+        let mut result = String::with_capacity(1 + encrypted.len().div_ceil(3) * 4);
+
+        base64::engine::general_purpose::STANDARD.encode_string(&encrypted, &mut result);
+
+        let padding = result.bytes().rev().take_while(|n| *n == b'=').count();
+
+        result.truncate(result.len() - padding);
+
+        self.insert_base(&mut result, 0, base_char.encode_utf8(&mut [0; 4]));
+
+        if self.padding {
+            for _ in 0..padding {
+                result.push('=');
+            }
+        }
+
+        result
+    }
+
+    pub fn decrypt_base64<S: AsRef<str>>(&self, base64: S) -> Result<Vec<u8>, ShortCryptError> {
+        let base64 = self.strip_separators(base64.as_ref().as_bytes(), |_| false);
+        let bytes: &[u8] = &base64;
+
+        let padding = bytes.iter().rev().take_while(|n| **n == b'=').count();
+
+        let unpadded = &bytes[..(bytes.len() - padding)];
+
+        if unpadded.is_empty() {
+            return Err(ShortCryptError::DecodeBase64);
+        }
+
+        let base_index = self.find_base(unpadded, 1);
+
+        let base = match BASE_DECODING_TABLE[unpadded[base_index] as usize] {
+            0xFF => return Err(ShortCryptError::DecodeBase64),
+            base => base,
+        };
+
+        let encrypted_base64 = [&bytes[..base_index], &bytes[(base_index + 1)..]].concat();
+
+        let encrypted = if self.padding {
+            base64::engine::general_purpose::STANDARD.decode(encrypted_base64)
+        } else {
+            base64::engine::general_purpose::STANDARD_NO_PAD.decode(encrypted_base64)
+        }
+        .map_err(|_| ShortCryptError::DecodeBase64)?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// Create a DNS label (at most 63 lowercase letters and digits). The body is encoded by lowercase Base32, and the label always starts with a check letter so that it never starts with a digit. Plaintexts longer than 38 bytes cannot fit in a label.
+    pub fn encrypt_to_dns_label<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+    ) -> Result<String, ShortCryptError> {
+        let data = data.as_ref();
+
+        if data.len() > 38 {
+            return Err(ShortCryptError::DataTooLong);
+        }
+
+        let mut result = String::with_capacity(2 + (data.len() * 8).div_ceil(5));
+
+        result.push('a');
+
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        bits::encode(&encrypted, DNS_LABEL_ALPHABET, 5, &mut result);
+
+        self.insert_base_symbols(&mut result, 1, DNS_LABEL_ALPHABET, base);
+
+        let check_letter = dns_label_check_letter(&result.as_bytes()[1..]) as char;
+
+        result.replace_range(..1, check_letter.encode_utf8(&mut [0; 4]));
+
+        Ok(result)
+    }
+
+    pub fn decrypt_dns_label<S: AsRef<str>>(
+        &self,
+        dns_label: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let dns_label = self.strip_separators(dns_label.as_ref().as_bytes(), |_| false);
+        let bytes: &[u8] = &dns_label;
+        let len = bytes.len();
+
+        if !(2..=63).contains(&len) || bytes[0] != dns_label_check_letter(&bytes[1..]) {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        self.decrypt_bits(&bytes[1..], &DNS_LABEL_DECODING_TABLE, 5)
+    }
+
+    /// Every two bytes of the body become one proquint (a pronounceable five-letter word), and the words are joined with `-`. The base (along with whether the body has an odd length) also takes one word.
+    pub fn encrypt_to_proquint<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut values: Vec<u16> = Vec::with_capacity(2 + encrypted.len() / 2);
+
+        for chunk in encrypted.chunks(2) {
+            values.push(match chunk {
+                [a, b] => u16::from_be_bytes([*a, *b]),
+                _ => u16::from(chunk[0]) << 8,
+            });
+        }
+
+        let base_word = u16::from(base) | (((encrypted.len() % 2) as u16) << 5);
+
+        let mut sum = u64::from(base_word);
+
+        for v in values.iter().copied() {
+            sum = sum.wrapping_add(u64::from(v));
+        }
+
+        let base_index = self.base_index(sum, values.len() + 1);
+
+        values.insert(base_index, base_word);
+
+        let mut result = String::with_capacity(values.len() * 6);
+
+        for (i, v) in values.iter().copied().enumerate() {
+            if i > 0 {
+                result.push('-');
+            }
+
+            for n in proquint::encode(v).iter().copied() {
+                result.push(n as char);
+            }
+        }
+
+        result
+    }
+
+    /// The proquints are case-insensitive.
+    pub fn decrypt_proquint<S: AsRef<str>>(&self, proquint: S) -> Result<Vec<u8>, ShortCryptError> {
+        let proquint = self.strip_separators(proquint.as_ref().as_bytes(), |n| n == b'-');
+
+        let mut values: Vec<u16> = Vec::new();
+        let mut sum = 0u64;
+
+        for word in proquint.split(|n| *n == b'-') {
+            let v = proquint::decode(word).ok_or(ShortCryptError::InvalidText)?;
+
+            values.push(v);
+            sum = sum.wrapping_add(u64::from(v));
+        }
+
+        let base_index = self.base_index(sum, values.len());
+
+        let base_word = values.remove(base_index);
+
+        if base_word > 0x3F {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let base = (base_word & 0x1F) as u8;
+        let odd = base_word >> 5 == 1;
+
+        let mut encrypted = Vec::with_capacity(values.len() * 2);
+
+        for v in values.iter().copied() {
+            encrypted.extend_from_slice(&v.to_be_bytes());
+        }
+
+        if odd && encrypted.pop().is_none() {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// Create a Bech32 string whose human-readable part is `hrp`. The base is inserted into the data part before the checksum is computed, so the checksum also covers it. The 90-character limit of BIP-0173 is not enforced.
+    pub fn encrypt_to_bech32<H: AsRef<str>, T: ?Sized + AsRef<[u8]>>(
+        &self,
+        hrp: H,
+        data: &T,
+    ) -> Result<String, ShortCryptError> {
+        let hrp = hrp.as_ref();
+
+        if !bech32::is_valid_hrp(hrp.as_bytes()) {
+            return Err(ShortCryptError::InvalidHrp);
+        }
+
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut result = String::with_capacity(hrp.len() + 8 + (encrypted.len() * 8).div_ceil(5));
+
+        result.push_str(hrp);
+        result.push('1');
+
+        let start = result.len();
+
+        bits::encode(&encrypted, bech32::ALPHABET, 5, &mut result);
+
+        self.insert_base_symbols(&mut result, start, bech32::ALPHABET, base);
+
+        let values: Vec<u8> =
+            result.bytes().skip(start).map(|n| BECH32_DECODING_TABLE[n as usize]).collect();
+
+        for v in bech32::create_checksum(hrp.as_bytes(), &values).iter().copied() {
+            result.push(bech32::ALPHABET[v as usize] as char);
+        }
+
+        Ok(result)
+    }
+
+    /// Decrypt a Bech32 string whose human-readable part must be `hrp`. The checksum is verified before decryption. Either lowercase or uppercase input is accepted, but not mixed case.
+    pub fn decrypt_bech32<H: AsRef<str>, S: AsRef<str>>(
+        &self,
+        hrp: H,
+        bech32: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let hrp = hrp.as_ref().as_bytes();
+        let bech32 = self.strip_separators(bech32.as_ref().as_bytes(), |_| false);
+        let bytes: &[u8] = &bech32;
+
+        if bytes.iter().any(|n| n.is_ascii_lowercase())
+            && bytes.iter().any(|n| n.is_ascii_uppercase())
+        {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let bytes = bytes.to_ascii_lowercase();
+
+        let separator_index = match bytes.iter().rposition(|n| *n == b'1') {
+            Some(index) => index,
+            None => return Err(ShortCryptError::InvalidText),
+        };
+
+        if &bytes[..separator_index] != hrp {
+            return Err(ShortCryptError::InvalidHrp);
+        }
+
+        let data_part = &bytes[(separator_index + 1)..];
+
+        if data_part.len() < 7 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let mut values = Vec::with_capacity(data_part.len());
+
+        for n in data_part.iter().copied() {
+            let v = BECH32_DECODING_TABLE[n as usize];
+
+            if v == 0xFF {
+                return Err(ShortCryptError::InvalidText);
+            }
+
+            values.push(v);
+        }
+
+        if !bech32::verify_checksum(hrp, &values) {
+            return Err(ShortCryptError::InvalidChecksum);
+        }
+
+        self.decrypt_bits(&data_part[..(data_part.len() - 6)], &BECH32_DECODING_TABLE, 5)
+    }
+
+    /// Uppercase letters only. The base takes two letters.
+    pub fn encrypt_to_base26<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_radix(data.as_ref(), BASE26_ALPHABET)
+    }
+
+    /// The input is case-insensitive.
+    pub fn decrypt_base26<S: AsRef<str>>(&self, base26: S) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_radix(
+            &base26.as_ref().as_bytes().to_ascii_uppercase(),
+            &BASE26_DECODING_TABLE,
+            26,
+        )
+    }
+
+    /// Format the cipher as a UUID string (8-4-4-4-12 lowercase hexadecimal digits) with the version `8` and the variant `10` of RFC 9562, so it passes UUID validators which check them. The other 122 bits are the 5-bit base, the body, a `1` bit and zero bits, masked with the key. Only plaintexts no longer than 14 bytes can be formatted.
+    pub fn encrypt_to_uuid<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+    ) -> Result<String, ShortCryptError> {
+        let data = data.as_ref();
+
+        if data.len() > 14 {
+            return Err(ShortCryptError::DataTooLong);
+        }
+
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let mut value = u128::from(base) << 117;
+
+        let mut shift = 117;
+
+        for n in encrypted.iter().copied() {
+            shift -= 8;
+
+            value |= u128::from(n) << shift;
+        }
+
+        value |= 1 << (shift - 1);
+
+        value ^= self.uuid_mask();
+
+        let mut result = String::with_capacity(36);
+
+        for (i, n) in uuid_from_payload(value).to_be_bytes().iter().copied().enumerate() {
+            if i == 4 || i == 6 || i == 8 || i == 10 {
+                result.push('-');
+            }
+
+            result.push(HEX_ALPHABET[(n >> 4) as usize] as char);
+            result.push(HEX_ALPHABET[(n & 0xF) as usize] as char);
+        }
+
+        Ok(result)
+    }
+
+    /// The input is case-insensitive.
+    pub fn decrypt_uuid<S: AsRef<str>>(&self, uuid: S) -> Result<Vec<u8>, ShortCryptError> {
+        let uuid = self.strip_separators(uuid.as_ref().as_bytes(), |n| n == b'-');
+        let bytes: &[u8] = &uuid;
+
+        if bytes.len() != 36 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let mut value = 0u128;
+
+        for (i, n) in bytes.iter().copied().enumerate() {
+            if i == 8 || i == 13 || i == 18 || i == 23 {
+                if n != b'-' {
+                    return Err(ShortCryptError::InvalidText);
+                }
+
+                continue;
+            }
+
+            let d = HEX_DECODING_TABLE[n.to_ascii_lowercase() as usize];
+
+            if d == 0xFF {
+                return Err(ShortCryptError::InvalidText);
+            }
+
+            value = (value << 4) | u128::from(d);
+        }
+
+        let mut value = uuid_to_payload(value).ok_or(ShortCryptError::InvalidText)?;
+
+        value ^= self.uuid_mask();
+
+        let base = (value >> 117) as u8;
+
+        let rest = value & ((1 << 117) - 1);
+
+        if rest == 0 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let marker = rest.trailing_zeros() as usize;
+
+        let body_bits = 116 - marker;
+
+        if body_bits % 8 != 0 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let len = body_bits / 8;
+
+        let body = (rest >> (marker + 1)).to_be_bytes();
+
+        self.decrypt_slice(base, &body[(16 - len)..])
+    }
+
+    /// Recognize the format of a text by its characters and decrypt it. The candidate formats are tried in the order of `Format::Uuid`, `Format::QrCodeNumeric`, `Format::Hex`, `Format::QrCodeAlphanumeric`, `Format::UrlComponent` and `Format::Emoji`, and the first one which can decode the text is returned along with the plaintext.
+    ///
+    /// Since the character sets of these formats overlap, a text created by a later format may be recognized as an earlier one if all of its characters happen to be in the earlier character set.
+    pub fn decrypt_any<S: AsRef<str>>(
+        &self,
+        text: S,
+    ) -> Result<(Format, Vec<u8>), ShortCryptError> {
+        let text = self.strip_separators(text.as_ref().as_bytes(), |n| n == b'-');
+        let text = core::str::from_utf8(&text).map_err(|_| ShortCryptError::InvalidText)?;
+        let bytes = text.as_bytes();
+
+        if bytes.len() == 36 {
+            if let Ok(plaintext) = self.decrypt_uuid(text) {
+                return Ok((Format::Uuid, plaintext));
+            }
+        }
+
+        if bytes.iter().all(|n| n.is_ascii_digit()) {
+            if let Ok(plaintext) = self.decrypt_qr_code_numeric(text) {
+                return Ok((Format::QrCodeNumeric, plaintext));
+            }
+        }
+
+        if bytes.iter().all(|n| HEX_DECODING_TABLE[*n as usize] != 0xFF) {
+            if let Ok(plaintext) = self.decrypt_hex(text) {
+                return Ok((Format::Hex, plaintext));
+            }
+        }
+
+        if bytes.iter().all(|n| n.is_ascii_digit() || n.is_ascii_uppercase()) {
+            if let Ok(plaintext) = self.decrypt_qr_code_alphanumeric(text) {
+                return Ok((Format::QrCodeAlphanumeric, plaintext));
+            }
+        }
+
+        if bytes.iter().all(|n| n.is_ascii_alphanumeric() || *n == b'-' || *n == b'_') {
+            if let Ok(plaintext) = self.decrypt_url_component(text) {
+                return Ok((Format::UrlComponent, plaintext));
+            }
+        }
+
+        if !text.is_ascii() {
+            if let Ok(plaintext) = self.decrypt_emoji(text) {
+                return Ok((Format::Emoji, plaintext));
+            }
+        }
+
+        Err(ShortCryptError::UnknownFormat)
+    }
+
+    /// Encrypt `data` to the format set by `ShortCryptBuilder::format`, which is `Format::UrlComponent` by default. Only `Format::Uuid` can fail, when the plaintext is too long.
+    pub fn encrypt_to_text<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+    ) -> Result<String, ShortCryptError> {
+        Ok(match self.format {
+            Format::Uuid => return self.encrypt_to_uuid(data),
+            Format::QrCodeNumeric => self.encrypt_to_qr_code_numeric(data),
+            Format::Hex => self.encrypt_to_hex(data),
+            Format::QrCodeAlphanumeric => self.encrypt_to_qr_code_alphanumeric(data),
+            Format::UrlComponent => self.encrypt_to_url_component(data),
+            Format::Emoji => self.encrypt_to_emoji(data),
+        })
+    }
+
+    /// Decrypt a text created by `encrypt_to_text`, in the format set by `ShortCryptBuilder::format`.
+    pub fn decrypt_text<S: AsRef<str>>(&self, text: S) -> Result<Vec<u8>, ShortCryptError> {
+        match self.format {
+            Format::Uuid => self.decrypt_uuid(text),
+            Format::QrCodeNumeric => self.decrypt_qr_code_numeric(text),
+            Format::Hex => self.decrypt_hex(text),
+            Format::QrCodeAlphanumeric => self.decrypt_qr_code_alphanumeric(text),
+            Format::UrlComponent => self.decrypt_url_component(text),
+            Format::Emoji => self.decrypt_emoji(text),
+        }
+    }
+
+    /// Only characters in the Code 39 barcode character set (except the space) are used.
+    pub fn encrypt_to_code39<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_radix(data.as_ref(), CODE39_ALPHABET)
+    }
+
+    pub fn decrypt_code39<S: AsRef<str>>(&self, code39: S) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_radix(code39.as_ref().as_bytes(), &CODE39_DECODING_TABLE, 42)
+    }
+
+    /// Like `encrypt_to_qr_code_numeric`, but a Luhn check digit is appended.
+    pub fn encrypt_to_luhn_numeric<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let mut result = self.encrypt_to_qr_code_numeric(data);
+
+        let check_digit = luhn_check_digit(result.as_bytes());
+
+        result.push(check_digit as char);
+
+        result
+    }
+
+    /// The Luhn check digit is validated before decryption, so most typos can be detected.
+    pub fn decrypt_luhn_numeric<S: AsRef<str>>(
+        &self,
+        luhn_numeric: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let luhn_numeric = self.strip_separators(luhn_numeric.as_ref().as_bytes(), |_| false);
+        let bytes: &[u8] = &luhn_numeric;
+
+        let (check_digit, digits) = match bytes.split_last() {
+            Some((check_digit, digits)) if check_digit.is_ascii_digit() => (*check_digit, digits),
+            _ => return Err(ShortCryptError::InvalidText),
+        };
+
+        if !digits.iter().all(|n| n.is_ascii_digit()) {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        if luhn_check_digit(digits) != check_digit {
+            return Err(ShortCryptError::InvalidChecksum);
+        }
+
+        self.decrypt_radix(digits, &DECIMAL_DECODING_TABLE, 10)
+    }
+
+    /// Like `encrypt_to_qr_code_alphanumeric`, but the output is split into groups of `group_len` characters joined with `separator`, such as `3BHN-NR45-XZH8-PU`. The separator should not be a digit or an uppercase letter. If `group_len` is `0`, the output is not split.
+    pub fn encrypt_to_serial<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        group_len: usize,
+        separator: char,
+    ) -> String {
+        let qr_code_alphanumeric = self.encrypt_to_qr_code_alphanumeric(data);
+
+        if group_len == 0 {
+            return qr_code_alphanumeric;
+        }
+
+        let len = qr_code_alphanumeric.len();
+
+        let mut result = String::with_capacity(len + (len / group_len) * separator.len_utf8());
+
+        for (i, c) in qr_code_alphanumeric.chars().enumerate() {
+            if i > 0 && i % group_len == 0 {
+                result.push(separator);
+            }
+
+            result.push(c);
+        }
+
+        result
+    }
+
+    /// Decrypt a serial number created by `encrypt_to_serial`. All `separator` characters are ignored, so the grouping does not matter.
+    pub fn decrypt_serial<S: AsRef<str>>(
+        &self,
+        serial: S,
+        separator: char,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let qr_code_alphanumeric: String =
+            serial.as_ref().chars().filter(|c| *c != separator).collect();
+
+        self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)
+    }
+
+    pub fn encrypt_to_base36<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        self.encrypt_to_radix(data.as_ref(), BASE36_ALPHABET)
+    }
+
+    /// The input is case-insensitive.
+    pub fn decrypt_base36<S: AsRef<str>>(&self, base36: S) -> Result<Vec<u8>, ShortCryptError> {
+        self.decrypt_radix(
+            &base36.as_ref().as_bytes().to_ascii_uppercase(),
+            &BASE36_DECODING_TABLE,
+            36,
+        )
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the text is in lowercase.
+    pub fn encrypt_to_base32_lowercase<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let mut result = self.encrypt_to_qr_code_alphanumeric(data);
+
+        result.make_ascii_lowercase();
+
+        result
+    }
+
+    /// The input is case-insensitive, so texts created by `encrypt_to_qr_code_alphanumeric` can also be decrypted.
+    pub fn decrypt_base32_lowercase<S: AsRef<str>>(
+        &self,
+        base32: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, encrypted) =
+            self.decode_qr_code_alphanumeric(&base32.as_ref().as_bytes().to_ascii_uppercase())?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    pub fn encrypt_to_base32hex<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> String {
+        let data = data.as_ref();
+
+        let mut result = String::with_capacity(1 + (data.len() * 8).div_ceil(5));
+
+        self.encrypt_with_encoding(data, &Base32HexEncoding, &mut result);
+
+        result
+    }
+
+    pub fn decrypt_base32hex<S: AsRef<str>>(
+        &self,
+        base32hex: S,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let (base, encrypted) = self.decode_with_encoding(
+            base32hex.as_ref().as_bytes(),
+            &Base32HexEncoding,
+            ShortCryptError::DecodeBase32,
+        )?;
+
+        self.decrypt_vec(base, encrypted)
+    }
+
+    /// Encrypt data into lazily encoded characters with `alphabet` whose length is `2^bits`. The base symbol is in `base_symbols`.
+    fn encrypt_to_chars<'a>(
+        &self,
+        data: &[u8],
+        alphabet: &'a [u8],
+        bits: u32,
+        base_symbols: &[u8],
+    ) -> EncryptedChars<'a> {
+        let (base, encrypted) = self.encrypt(data).into_parts();
+
+        let base_char = base_symbols[base as usize] as char;
+
+        EncryptedChars::new(encrypted, alphabet, bits, base_char, self)
+    }
+
+    /// Encode a cipher with `alphabet` whose length is `2^bits` and write the text to `writer` without allocating a `String`.
+    fn write_bits<W: ?Sized + fmt::Write>(
+        &self,
+        data: &[u8],
+        alphabet: &[u8],
+        bits: u32,
+        writer: &mut W,
+    ) -> Result<(), fmt::Error> {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        self.write_cipher_bits(base, &encrypted, alphabet, bits, writer)
+    }
+
+    /// Encode the **body** of a cipher with `alphabet` whose length is `2^bits`, insert the base symbol, and write the text to `writer`. The **body** is walked twice instead of being buffered.
+    fn write_cipher_bits<W: ?Sized + fmt::Write>(
+        &self,
+        base: u8,
+        body: &[u8],
+        alphabet: &[u8],
+        bits: u32,
+        writer: &mut W,
+    ) -> Result<(), fmt::Error> {
+        let base_char = BASE_SYMBOLS[base as usize];
+
+        let mut sum = u64::from(base_char);
+        let mut body_len = 0;
+
+        for n in bits::Symbols::new(body, alphabet, bits) {
+            sum = sum.wrapping_add(u64::from(n));
+            body_len += 1;
+        }
+
+        let base_index = self.base_index(sum, body_len + 1);
+
+        for (i, n) in bits::Symbols::new(body, alphabet, bits).enumerate() {
+            if i == base_index {
+                writer.write_char(base_char as char)?;
+            }
+
+            writer.write_char(n as char)?;
+        }
+
+        if base_index == body_len {
+            writer.write_char(base_char as char)?;
+        }
+
+        Ok(())
+    }
+
+    /// Same as `encrypt_to_url_component`, but the text is written to a `core::fmt::Write` target (e.g. a formatter or a fixed-capacity string) directly.
+    #[inline]
+    pub fn encrypt_to_url_component_writer<T: ?Sized + AsRef<[u8]>, W: ?Sized + fmt::Write>(
+        &self,
+        data: &T,
+        writer: &mut W,
+    ) -> Result<(), fmt::Error> {
+        self.write_bits(data.as_ref(), URL_COMPONENT_ALPHABET, 6, writer)
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the text is written to a `core::fmt::Write` target (e.g. a formatter or a fixed-capacity string) directly.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_writer<
+        T: ?Sized + AsRef<[u8]>,
+        W: ?Sized + fmt::Write,
+    >(
+        &self,
+        data: &T,
+        writer: &mut W,
+    ) -> Result<(), fmt::Error> {
+        self.write_bits(data.as_ref(), BASE32_ALPHABET, 5, writer)
+    }
+
+    /// Create a `Display` value which encrypts `data` into a URL component while it is being formatted, e.g. `format!("https://magiclen.org/{}", sc.display_url_component("articles"))`.
+    #[inline]
+    pub fn display_url_component<'a, T: ?Sized + AsRef<[u8]>>(
+        &'a self,
+        data: &'a T,
+    ) -> DisplayUrlComponent<'a, T> {
+        DisplayUrlComponent::new(self, data)
+    }
+
+    /// Create a `Display` value which encrypts `data` into a QR code alphanumeric text while it is being formatted.
+    #[inline]
+    pub fn display_qr_code_alphanumeric<'a, T: ?Sized + AsRef<[u8]>>(
+        &'a self,
+        data: &'a T,
+    ) -> DisplayQrCodeAlphanumeric<'a, T> {
+        DisplayQrCodeAlphanumeric::new(self, data)
+    }
+
+    /// Encrypt `data` and push the cipher encoded with `alphabet` whose length is `2^bits` to `output`.
+    fn encrypt_to_output<B: ?Sized + OutputBuffer>(
+        &self,
+        data: &[u8],
+        alphabet: &[u8],
+        bits: u32,
+        output: &mut B,
+    ) -> Result<(), ShortCryptError> {
+        let len = 1 + (data.len() * 8).div_ceil(bits as usize);
+
+        if output.remaining_capacity() < len {
+            return Err(ShortCryptError::BufferTooSmall);
+        }
+
+        output.reserve(len);
+
+        // the capacity has been checked, so pushing never fails
+        let _ = self.write_bits(data, alphabet, bits, &mut OutputWriter(output));
+
+        Ok(())
+    }
+
+    /// Encrypt every item of `items` and encode it with `alphabet` whose length is `2^bits`, reusing one buffer for the **bodies**.
+    fn encrypt_batch_bits<T: AsRef<[u8]>>(
+        &self,
+        items: &[T],
+        alphabet: &[u8],
+        bits: u32,
+    ) -> Vec<String> {
+        let mut body =
+            Vec::with_capacity(items.iter().map(|item| item.as_ref().len()).max().unwrap_or(0));
+
+        items
+            .iter()
+            .map(|item| self.encrypt_bits_with_scratch(item.as_ref(), &mut body, alphabet, bits))
+            .collect()
+    }
+
+    /// Same as `encrypt_batch_bits`, but the items are split across the rayon thread pool.
+    #[cfg(feature = "rayon")]
+    fn par_encrypt_bits<T: AsRef<[u8]> + Sync>(
+        &self,
+        items: &[T],
+        alphabet: &[u8],
+        bits: u32,
+    ) -> Vec<String> {
+        use rayon::prelude::*;
+
+        items
+            .par_iter()
+            .map_init(Vec::new, |body, item| {
+                self.encrypt_bits_with_scratch(item.as_ref(), body, alphabet, bits)
+            })
+            .collect()
+    }
+
+    /// Encrypt `data` and encode it with `alphabet` whose length is `2^bits`, using `body` as the buffer of the **body**.
+    fn encrypt_bits_with_scratch(
+        &self,
+        data: &[u8],
+        body: &mut Vec<u8>,
+        alphabet: &[u8],
+        bits: u32,
+    ) -> String {
+        let mut text = String::with_capacity(1 + (data.len() * 8).div_ceil(bits as usize));
+
+        self.push_bits_with_scratch(data, body, alphabet, bits, &mut text);
+
+        text
+    }
+
+    /// Same as `encrypt_bits_with_scratch`, but the text is pushed to `text`.
+    fn push_bits_with_scratch(
+        &self,
+        data: &[u8],
+        body: &mut Vec<u8>,
+        alphabet: &[u8],
+        bits: u32,
+        text: &mut String,
+    ) {
+        body.clear();
+        body.extend_from_slice(data);
+
+        let base = self.encrypt_in_place(body);
+
+        // writing to a `String` never fails
+        let _ = self.write_cipher_bits(base, body, alphabet, bits, text);
+    }
+
+    /// Encode a cipher with `alphabet` whose length is `2^bits` into `buffer`. Returns the length of the text.
+    fn write_bits_to_buffer(
+        &self,
+        data: &[u8],
+        alphabet: &[u8],
+        bits: u32,
+        buffer: &mut [u8],
+    ) -> Result<usize, ShortCryptError> {
+        let len = 1 + (data.len() * 8).div_ceil(bits as usize);
+
+        if buffer.len() < len {
+            return Err(ShortCryptError::BufferTooSmall);
+        }
+
+        let mut writer = SliceWriter {
+            buffer,
+            len: 0,
+        };
+
+        self.write_bits(data, alphabet, bits, &mut writer)
+            .map_err(|_| ShortCryptError::BufferTooSmall)?;
+
+        Ok(writer.len)
+    }
+
+    /// Same as `encrypt_to_url_component`, but the ASCII text is written to `buffer`. Returns the length of the text, or an error if the buffer is too small.
+    #[inline]
+    pub fn encrypt_to_url_component_buf<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        buffer: &mut [u8],
+    ) -> Result<usize, ShortCryptError> {
+        self.write_bits_to_buffer(data.as_ref(), URL_COMPONENT_ALPHABET, 6, buffer)
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the ASCII text is written to `buffer`. Returns the length of the text, or an error if the buffer is too small.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_buf<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        buffer: &mut [u8],
+    ) -> Result<usize, ShortCryptError> {
+        self.write_bits_to_buffer(data.as_ref(), BASE32_ALPHABET, 5, buffer)
+    }
+
+    /// Same as `encrypt_to_url_component`, but the characters are encoded lazily by an iterator.
+    #[inline]
+    pub fn encrypt_to_url_component_chars<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+    ) -> EncryptedChars<'static> {
+        self.encrypt_to_chars(data.as_ref(), URL_COMPONENT_ALPHABET, 6, BASE_SYMBOLS)
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the characters are encoded lazily by an iterator.
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_chars<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+    ) -> EncryptedChars<'static> {
+        self.encrypt_to_chars(data.as_ref(), BASE32_ALPHABET, 5, BASE_SYMBOLS)
+    }
+
+    /// Same as `encrypt_to_custom_alphabet`, but the characters are encoded lazily by an iterator.
+    #[inline]
+    pub fn encrypt_to_custom_alphabet_chars<'a, T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        alphabet: &'a Alphabet,
+    ) -> EncryptedChars<'a> {
+        let symbols = alphabet.as_bytes();
+
+        self.encrypt_to_chars(data.as_ref(), symbols, alphabet.bits(), symbols)
+    }
+
+    /// Encrypt data into Shift JIS kanji characters for the kanji mode of a QR code, in which every character takes 13 bits. Each character carries 12 bits of the cipher, so this is denser than the alphanumeric mode. The base character also records whether the last character is padded with a whole byte.
+    pub fn encrypt_to_qr_code_kanji<T: ?Sized + AsRef<[u8]>>(&self, data: &T) -> Vec<u8> {
+        let (base, encrypted) = self.encrypt_inline(data);
+
+        let padding = encrypted.len() % 3 == 2;
+
+        let mut result = Vec::with_capacity(2 + (encrypted.len() * 8).div_ceil(12) * 2);
+
+        kanji::encode(&encrypted, &mut result);
+
+        let base_char = kanji::to_shift_jis(u16::from(base) | (u16::from(padding) << 5));
+
+        let mut sum = 0u64;
+
+        for n in base_char.iter().chain(result.iter()).copied() {
+            sum = sum.wrapping_add(u64::from(n));
+        }
+
+        let base_index = self.base_index(sum, result.len() / 2 + 1);
+
+        result.splice((base_index * 2)..(base_index * 2), base_char.iter().copied());
+
+        result
+    }
+
+    /// Decrypt Shift JIS bytes created by `encrypt_to_qr_code_kanji`.
+    pub fn decrypt_qr_code_kanji<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        shift_jis: &T,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let bytes = shift_jis.as_ref();
+        let len = bytes.len();
+
+        if len < 2 || len % 2 != 0 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let base_index = {
+            let mut sum = 0u64;
+
+            for n in bytes.iter().copied() {
+                sum = sum.wrapping_add(u64::from(n));
+            }
+
+            self.base_index(sum, len / 2)
+        };
+
+        let mut base = 0;
+        let mut values = Vec::with_capacity(len / 2 - 1);
+
+        for (i, c) in bytes.chunks_exact(2).enumerate() {
+            let v = kanji::from_shift_jis(c[0], c[1]).ok_or(ShortCryptError::InvalidText)?;
+
+            if i == base_index {
+                base = v;
+            } else {
+                values.push(v);
+            }
+        }
+
+        if base > 63 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let padding = base >> 5 == 1;
+
+        // only the last byte of a pair of values can be the padding byte, and it must be zero
+        if padding && values.len() % 2 == 1 {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        let mut encrypted = kanji::decode(&values).ok_or(ShortCryptError::InvalidText)?;
+
+        if padding && encrypted.pop() != Some(0) {
+            return Err(ShortCryptError::InvalidText);
+        }
+
+        self.decrypt_vec((base & 31) as u8, encrypted)
+    }
+
+    /// Encrypt a `u64` integer. The integer is converted to bytes in big-endian order.
+    #[inline]
+    pub fn encrypt_u64(&self, n: u64) -> Cipher {
+        self.encrypt(&n.to_be_bytes())
+    }
+
+    /// Decrypt a cipher created by `encrypt_u64`.
+    #[inline]
+    pub fn decrypt_u64(&self, data: &Cipher) -> Result<u64, ShortCryptError> {
+        Ok(u64::from_be_bytes(to_array(&self.decrypt(data)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_u64_to_url_component(&self, n: u64) -> String {
+        self.encrypt_to_url_component(&n.to_be_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_u64<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<u64, ShortCryptError> {
+        Ok(u64::from_be_bytes(to_array(&self.decrypt_url_component(url_component)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_u64_to_qr_code_alphanumeric(&self, n: u64) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&n.to_be_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_u64<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<u64, ShortCryptError> {
+        Ok(u64::from_be_bytes(to_array(&self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)?))
+    }
+
+    /// Encrypt a `u128` integer. The integer is converted to bytes in big-endian order.
+    #[inline]
+    pub fn encrypt_u128(&self, n: u128) -> Cipher {
+        self.encrypt(&n.to_be_bytes())
+    }
+
+    /// Decrypt a cipher created by `encrypt_u128`. The plaintext must be exactly 16 bytes.
+    #[inline]
+    pub fn decrypt_u128(&self, data: &Cipher) -> Result<u128, ShortCryptError> {
+        Ok(u128::from_be_bytes(to_array(&self.decrypt(data)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_u128_to_url_component(&self, n: u128) -> String {
+        self.encrypt_to_url_component(&n.to_be_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_u128<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<u128, ShortCryptError> {
+        Ok(u128::from_be_bytes(to_array(&self.decrypt_url_component(url_component)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_u128_to_qr_code_alphanumeric(&self, n: u128) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&n.to_be_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_u128<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<u128, ShortCryptError> {
+        Ok(u128::from_be_bytes(to_array(
+            &self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?,
+        )?))
+    }
+
+    /// Encrypt a UUID. The 16 bytes of the UUID are encrypted in big-endian order.
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn encrypt_uuid(&self, uuid: &uuid::Uuid) -> Cipher {
+        self.encrypt(uuid.as_bytes())
+    }
+
+    /// Decrypt a cipher created by `encrypt_uuid`. The plaintext must be exactly 16 bytes.
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn decrypt_to_uuid(&self, data: &Cipher) -> Result<uuid::Uuid, ShortCryptError> {
+        Ok(uuid::Uuid::from_bytes(to_array(&self.decrypt(data)?)?))
+    }
+
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn encrypt_uuid_to_url_component(&self, uuid: &uuid::Uuid) -> String {
+        self.encrypt_to_url_component(uuid.as_bytes())
+    }
+
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn decrypt_url_component_to_uuid<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<uuid::Uuid, ShortCryptError> {
+        Ok(uuid::Uuid::from_bytes(to_array(&self.decrypt_url_component(url_component)?)?))
+    }
+
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn encrypt_uuid_to_qr_code_alphanumeric(&self, uuid: &uuid::Uuid) -> String {
+        self.encrypt_to_qr_code_alphanumeric(uuid.as_bytes())
+    }
+
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_uuid<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<uuid::Uuid, ShortCryptError> {
+        Ok(uuid::Uuid::from_bytes(to_array(
+            &self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?,
+        )?))
+    }
+
+    /// Encrypt a UUID into a text with an `OutputEncoding`.
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn encrypt_uuid_to<E: ?Sized + OutputEncoding>(
+        &self,
+        uuid: &uuid::Uuid,
+        encoding: &E,
+    ) -> String {
+        self.encrypt_to(uuid.as_bytes(), encoding)
+    }
+
+    /// Decrypt a text created by `encrypt_uuid_to` with the same `OutputEncoding`.
+    #[cfg(feature = "uuid")]
+    #[inline]
+    pub fn decrypt_from_to_uuid<E: ?Sized + OutputEncoding, S: AsRef<str>>(
+        &self,
+        text: S,
+        encoding: &E,
+    ) -> Result<uuid::Uuid, ShortCryptError> {
+        Ok(uuid::Uuid::from_bytes(to_array(&self.decrypt_from(text, encoding)?)?))
+    }
+
+    /// Encrypt a `u64` integer encoded in LEB128 (unsigned varint), so a small integer produces a short cipher. For example, an integer smaller than 128 becomes a 1-byte body.
+    #[inline]
+    pub fn encrypt_u64_varint(&self, n: u64) -> Cipher {
+        let (buffer, len) = varint::encode(n);
+
+        self.encrypt(&buffer[..len])
+    }
+
+    /// Decrypt a cipher created by `encrypt_u64_varint`.
+    #[inline]
+    pub fn decrypt_u64_varint(&self, data: &Cipher) -> Result<u64, ShortCryptError> {
+        varint::decode(&self.decrypt(data)?).ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_u64_varint_to_url_component(&self, n: u64) -> String {
+        let (buffer, len) = varint::encode(n);
+
+        self.encrypt_to_url_component(&buffer[..len])
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_u64_varint<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<u64, ShortCryptError> {
+        varint::decode(&self.decrypt_url_component(url_component)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_u64_varint_to_qr_code_alphanumeric(&self, n: u64) -> String {
+        let (buffer, len) = varint::encode(n);
+
+        self.encrypt_to_qr_code_alphanumeric(&buffer[..len])
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_u64_varint<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<u64, ShortCryptError> {
+        varint::decode(&self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    /// Encrypt an IPv4 address. The 4 octets are encrypted in network order.
+    #[inline]
+    pub fn encrypt_ipv4(&self, ip: &Ipv4Addr) -> Cipher {
+        self.encrypt(&ip.octets())
+    }
+
+    /// Decrypt a cipher created by `encrypt_ipv4`. The plaintext must be exactly 4 bytes.
+    #[inline]
+    pub fn decrypt_ipv4(&self, data: &Cipher) -> Result<Ipv4Addr, ShortCryptError> {
+        Ok(Ipv4Addr::from(to_array::<4>(&self.decrypt(data)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_ipv4_to_url_component(&self, ip: &Ipv4Addr) -> String {
+        self.encrypt_to_url_component(&ip.octets())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_ipv4<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<Ipv4Addr, ShortCryptError> {
+        Ok(Ipv4Addr::from(to_array::<4>(&self.decrypt_url_component(url_component)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_ipv4_to_qr_code_alphanumeric(&self, ip: &Ipv4Addr) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&ip.octets())
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_ipv4<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<Ipv4Addr, ShortCryptError> {
+        Ok(Ipv4Addr::from(to_array::<4>(
+            &self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?,
+        )?))
+    }
+
+    /// Encrypt an IPv6 address. The 16 octets are encrypted in network order.
+    #[inline]
+    pub fn encrypt_ipv6(&self, ip: &Ipv6Addr) -> Cipher {
+        self.encrypt(&ip.octets())
+    }
+
+    /// Decrypt a cipher created by `encrypt_ipv6`. The plaintext must be exactly 16 bytes.
+    #[inline]
+    pub fn decrypt_ipv6(&self, data: &Cipher) -> Result<Ipv6Addr, ShortCryptError> {
+        Ok(Ipv6Addr::from(to_array::<16>(&self.decrypt(data)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_ipv6_to_url_component(&self, ip: &Ipv6Addr) -> String {
+        self.encrypt_to_url_component(&ip.octets())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_ipv6<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<Ipv6Addr, ShortCryptError> {
+        Ok(Ipv6Addr::from(to_array::<16>(&self.decrypt_url_component(url_component)?)?))
+    }
+
+    #[inline]
+    pub fn encrypt_ipv6_to_qr_code_alphanumeric(&self, ip: &Ipv6Addr) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&ip.octets())
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_ipv6<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<Ipv6Addr, ShortCryptError> {
+        Ok(Ipv6Addr::from(to_array::<16>(
+            &self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?,
+        )?))
+    }
+
+    /// Same as `decrypt_url_component`, but the plaintext must be valid UTF-8 and is returned as a `String`.
+    #[inline]
+    pub fn decrypt_url_component_to_string<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<String, ShortCryptError> {
+        String::from_utf8(self.decrypt_url_component(url_component)?)
+            .map_err(|_| ShortCryptError::Utf8)
+    }
+
+    /// Same as `decrypt_qr_code_alphanumeric`, but the plaintext must be valid UTF-8 and is returned as a `String`.
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_string<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<String, ShortCryptError> {
+        String::from_utf8(self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)
+            .map_err(|_| ShortCryptError::Utf8)
+    }
+
+    /// Serialize a value with postcard and encrypt it.
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn encrypt_value<T: ?Sized + ::serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<Cipher, ShortCryptError> {
+        Ok(self.encrypt(&postcard::to_allocvec(value).map_err(|_| ShortCryptError::Serialize)?))
+    }
+
+    /// Decrypt a cipher created by `encrypt_value` and deserialize the value.
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn decrypt_value<T: ::serde::de::DeserializeOwned>(
+        &self,
+        data: &Cipher,
+    ) -> Result<T, ShortCryptError> {
+        postcard::from_bytes(&self.decrypt(data)?).map_err(|_| ShortCryptError::Deserialize)
+    }
+
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn encrypt_value_to_url_component<T: ?Sized + ::serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<String, ShortCryptError> {
+        Ok(self.encrypt_to_url_component(
+            &postcard::to_allocvec(value).map_err(|_| ShortCryptError::Serialize)?,
+        ))
+    }
+
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn decrypt_url_component_to_value<T: ::serde::de::DeserializeOwned, S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<T, ShortCryptError> {
+        postcard::from_bytes(&self.decrypt_url_component(url_component)?)
+            .map_err(|_| ShortCryptError::Deserialize)
+    }
+
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn encrypt_value_to_qr_code_alphanumeric<T: ?Sized + ::serde::Serialize>(
+        &self,
+        value: &T,
+    ) -> Result<String, ShortCryptError> {
+        Ok(self.encrypt_to_qr_code_alphanumeric(
+            &postcard::to_allocvec(value).map_err(|_| ShortCryptError::Serialize)?,
+        ))
+    }
+
+    #[cfg(feature = "serde")]
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_value<
+        T: ::serde::de::DeserializeOwned,
+        S: AsRef<str>,
+    >(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<T, ShortCryptError> {
+        postcard::from_bytes(&self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)
+            .map_err(|_| ShortCryptError::Deserialize)
+    }
+
+    /// Encrypt data into a URL component which expires after `ttl` seconds. `now` is the current time in seconds (e.g. since the Unix epoch), and the expiration time is embedded in the **body** as a LEB128 integer before the data.
+    pub fn encrypt_with_ttl<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        data: &T,
+        now: u64,
+        ttl: u64,
+    ) -> String {
+        let data = data.as_ref();
+
+        let (buffer, len) = varint::encode(now.saturating_add(ttl));
+
+        let mut plaintext = Vec::with_capacity(len + data.len());
+
+        plaintext.extend_from_slice(&buffer[..len]);
+        plaintext.extend_from_slice(data);
+
+        self.encrypt_to_url_component(&plaintext)
+    }
+
+    /// Decrypt a URL component created by `encrypt_with_ttl`. `now` must be in the same unit and epoch as the one used for encryption. Returns an error if the token has expired.
+    pub fn decrypt_timed<S: AsRef<str>>(
+        &self,
+        token: S,
+        now: u64,
+    ) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = self.decrypt_url_component(token)?;
+
+        let (expires_at, len) =
+            varint::decode_prefix(&plaintext).ok_or(ShortCryptError::InvalidPayload)?;
+
+        if now >= expires_at {
+            return Err(ShortCryptError::Expired);
+        }
+
+        plaintext.drain(..len);
+
+        Ok(plaintext)
+    }
+
+    /// Encrypt a pagination cursor into a URL component.
+    #[inline]
+    pub fn encrypt_cursor(&self, cursor: &cursor::Cursor) -> String {
+        self.encrypt_to_url_component(&cursor.to_bytes())
+    }
+
+    /// Decrypt a URL component created by `encrypt_cursor`.
+    #[inline]
+    pub fn decrypt_cursor<S: AsRef<str>>(
+        &self,
+        cursor: S,
+    ) -> Result<cursor::Cursor, ShortCryptError> {
+        cursor::Cursor::from_bytes(&self.decrypt_url_component(cursor)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    /// Encrypt several fields into one cipher. Each field is prefixed with its length, so the fields can contain any bytes.
+    #[inline]
+    pub fn encrypt_fields<F: AsRef<[u8]>>(&self, fields: &[F]) -> Cipher {
+        self.encrypt(&fields::pack(fields))
+    }
+
+    /// Decrypt a cipher created by `encrypt_fields`.
+    #[inline]
+    pub fn decrypt_fields(&self, data: &Cipher) -> Result<Vec<Vec<u8>>, ShortCryptError> {
+        fields::unpack(&self.decrypt(data)?).ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_fields_to_url_component<F: AsRef<[u8]>>(&self, fields: &[F]) -> String {
+        self.encrypt_to_url_component(&fields::pack(fields))
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_fields<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<Vec<Vec<u8>>, ShortCryptError> {
+        fields::unpack(&self.decrypt_url_component(url_component)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_fields_to_qr_code_alphanumeric<F: AsRef<[u8]>>(&self, fields: &[F]) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&fields::pack(fields))
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_fields<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<Vec<Vec<u8>>, ShortCryptError> {
+        fields::unpack(&self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    /// Encrypt an `i64` integer with zigzag and LEB128 encoding, so an integer close to zero produces a short cipher whether it is negative or not.
+    #[inline]
+    pub fn encrypt_i64(&self, n: i64) -> Cipher {
+        self.encrypt_u64_varint(varint::zigzag_encode(n))
+    }
+
+    /// Decrypt a cipher created by `encrypt_i64`.
+    #[inline]
+    pub fn decrypt_i64(&self, data: &Cipher) -> Result<i64, ShortCryptError> {
+        self.decrypt_u64_varint(data).map(varint::zigzag_decode)
+    }
+
+    #[inline]
+    pub fn encrypt_i64_to_url_component(&self, n: i64) -> String {
+        self.encrypt_u64_varint_to_url_component(varint::zigzag_encode(n))
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_i64<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<i64, ShortCryptError> {
+        self.decrypt_url_component_to_u64_varint(url_component).map(varint::zigzag_decode)
+    }
+
+    #[inline]
+    pub fn encrypt_i64_to_qr_code_alphanumeric(&self, n: i64) -> String {
+        self.encrypt_u64_varint_to_qr_code_alphanumeric(varint::zigzag_encode(n))
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_i64<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<i64, ShortCryptError> {
+        self.decrypt_qr_code_alphanumeric_to_u64_varint(qr_code_alphanumeric)
+            .map(varint::zigzag_decode)
+    }
+
+    /// Encrypt a `chrono::DateTime<Utc>` as a timestamp with the given precision. The sub-precision part is truncated.
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn encrypt_date_time(
+        &self,
+        date_time: &chrono::DateTime<chrono::Utc>,
+        precision: TimestampPrecision,
+    ) -> Cipher {
+        self.encrypt_i64(timestamp::from_date_time(date_time, precision))
+    }
+
+    /// Decrypt a cipher created by `encrypt_date_time` with the same precision.
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn decrypt_date_time(
+        &self,
+        data: &Cipher,
+        precision: TimestampPrecision,
+    ) -> Result<chrono::DateTime<chrono::Utc>, ShortCryptError> {
+        timestamp::to_date_time(self.decrypt_i64(data)?, precision)
+    }
+
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn encrypt_date_time_to_url_component(
+        &self,
+        date_time: &chrono::DateTime<chrono::Utc>,
+        precision: TimestampPrecision,
+    ) -> String {
+        self.encrypt_i64_to_url_component(timestamp::from_date_time(date_time, precision))
+    }
+
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn decrypt_url_component_to_date_time<S: AsRef<str>>(
+        &self,
+        url_component: S,
+        precision: TimestampPrecision,
+    ) -> Result<chrono::DateTime<chrono::Utc>, ShortCryptError> {
+        timestamp::to_date_time(self.decrypt_url_component_to_i64(url_component)?, precision)
+    }
+
+    /// Encrypt a `time::OffsetDateTime` as a timestamp with the given precision. The offset is not kept and the sub-precision part is truncated.
+    #[cfg(feature = "time")]
+    #[inline]
+    pub fn encrypt_offset_date_time(
+        &self,
+        date_time: &time::OffsetDateTime,
+        precision: TimestampPrecision,
+    ) -> Cipher {
+        self.encrypt_i64(timestamp::from_offset_date_time(date_time, precision))
+    }
+
+    /// Decrypt a cipher created by `encrypt_offset_date_time` with the same precision. The offset of the returned value is UTC.
+    #[cfg(feature = "time")]
+    #[inline]
+    pub fn decrypt_offset_date_time(
+        &self,
+        data: &Cipher,
+        precision: TimestampPrecision,
+    ) -> Result<time::OffsetDateTime, ShortCryptError> {
+        timestamp::to_offset_date_time(self.decrypt_i64(data)?, precision)
+    }
+
+    #[cfg(feature = "time")]
+    #[inline]
+    pub fn encrypt_offset_date_time_to_url_component(
+        &self,
+        date_time: &time::OffsetDateTime,
+        precision: TimestampPrecision,
+    ) -> String {
+        self.encrypt_i64_to_url_component(timestamp::from_offset_date_time(date_time, precision))
+    }
+
+    #[cfg(feature = "time")]
+    #[inline]
+    pub fn decrypt_url_component_to_offset_date_time<S: AsRef<str>>(
+        &self,
+        url_component: S,
+        precision: TimestampPrecision,
+    ) -> Result<time::OffsetDateTime, ShortCryptError> {
+        timestamp::to_offset_date_time(self.decrypt_url_component_to_i64(url_component)?, precision)
+    }
+
+    /// Encrypt an `f64` number. Its IEEE 754 bit pattern is converted to bytes in big-endian order, so every value (including NaN payloads and the sign of zero) round-trips exactly.
+    #[inline]
+    pub fn encrypt_f64(&self, n: f64) -> Cipher {
+        self.encrypt_u64(n.to_bits())
+    }
+
+    /// Decrypt a cipher created by `encrypt_f64`.
+    #[inline]
+    pub fn decrypt_f64(&self, data: &Cipher) -> Result<f64, ShortCryptError> {
+        self.decrypt_u64(data).map(f64::from_bits)
+    }
+
+    #[inline]
+    pub fn encrypt_f64_to_url_component(&self, n: f64) -> String {
+        self.encrypt_u64_to_url_component(n.to_bits())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_f64<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<f64, ShortCryptError> {
+        self.decrypt_url_component_to_u64(url_component).map(f64::from_bits)
+    }
+
+    #[inline]
+    pub fn encrypt_f64_to_qr_code_alphanumeric(&self, n: f64) -> String {
+        self.encrypt_u64_to_qr_code_alphanumeric(n.to_bits())
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_f64<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<f64, ShortCryptError> {
+        self.decrypt_qr_code_alphanumeric_to_u64(qr_code_alphanumeric).map(f64::from_bits)
+    }
+
+    /// Create a new `ShortCrypt` instance with the key stored in the file at `path`. Trailing newlines (`\n` or `\r\n`) are trimmed, so the file can be written by `echo` or a text editor. The other bytes are used as they are, like `new_bytes`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_key_file<P: AsRef<std::path::Path>>(path: P) -> Result<ShortCrypt, KeyFileError> {
+        Ok(ShortCrypt::new_bytes(key_file::read(path.as_ref(), false)?))
+    }
+
+    /// Same as `from_key_file`, but on Unix the file must not be accessible by the group or other users (e.g. mode `600` or `400`), otherwise `KeyFileError::InsecurePermissions` is returned. On other platforms, the permissions are not checked.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_key_file_checked<P: AsRef<std::path::Path>>(
+        path: P,
+    ) -> Result<ShortCrypt, KeyFileError> {
+        Ok(ShortCrypt::new_bytes(key_file::read(path.as_ref(), true)?))
+    }
+
+    /// Encrypt a path into a URL component. Non-UTF-8 paths are supported on Unix and Windows. The token can only be decrypted on the same kind of platform.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn encrypt_path<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+    ) -> Result<String, ShortCryptError> {
+        Ok(self.encrypt_to_url_component(&path::to_bytes(path.as_ref())?))
+    }
+
+    /// Decrypt a URL component created by `encrypt_path`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn decrypt_to_path_buf<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<std::path::PathBuf, ShortCryptError> {
+        path::from_bytes(self.decrypt_url_component(url_component)?)
+    }
+
+    /// Encrypt a MAC address.
+    #[inline]
+    pub fn encrypt_mac(&self, mac: [u8; 6]) -> Cipher {
+        self.encrypt(&mac)
+    }
+
+    /// Decrypt a cipher created by `encrypt_mac`. The plaintext must be exactly 6 bytes.
+    #[inline]
+    pub fn decrypt_mac(&self, data: &Cipher) -> Result<[u8; 6], ShortCryptError> {
+        to_array(&self.decrypt(data)?)
+    }
+
+    #[inline]
+    pub fn encrypt_mac_to_url_component(&self, mac: [u8; 6]) -> String {
+        self.encrypt_to_url_component(&mac)
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_mac<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<[u8; 6], ShortCryptError> {
+        to_array(&self.decrypt_url_component(url_component)?)
+    }
+
+    /// Encrypt a MAC address into a QR code alphanumeric text, which is 11 characters long.
+    #[inline]
+    pub fn encrypt_mac_to_qr_code_alphanumeric(&self, mac: [u8; 6]) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&mac)
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_mac<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<[u8; 6], ShortCryptError> {
+        to_array(&self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)
+    }
+
+    #[inline]
+    pub fn encrypt_token_map_to_url_component(&self, map: &TokenMap) -> String {
+        self.encrypt_to_url_component(&map.to_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_token_map<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<TokenMap, ShortCryptError> {
+        TokenMap::from_bytes(&self.decrypt_url_component(url_component)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_token_map_to_qr_code_alphanumeric(&self, map: &TokenMap) -> String {
+        self.encrypt_to_qr_code_alphanumeric(&map.to_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_to_token_map<S: AsRef<str>>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<TokenMap, ShortCryptError> {
+        TokenMap::from_bytes(&self.decrypt_qr_code_alphanumeric(qr_code_alphanumeric)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_capability(&self, capability: &Capability) -> Cipher {
+        self.encrypt(&capability.to_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_capability(&self, data: &Cipher) -> Result<Capability, ShortCryptError> {
+        Capability::from_bytes(&self.decrypt(data)?).ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    #[inline]
+    pub fn encrypt_capability_to_url_component(&self, capability: &Capability) -> String {
+        self.encrypt_to_url_component(&capability.to_bytes())
+    }
+
+    #[inline]
+    pub fn decrypt_url_component_to_capability<S: AsRef<str>>(
+        &self,
+        url_component: S,
+    ) -> Result<Capability, ShortCryptError> {
+        Capability::from_bytes(&self.decrypt_url_component(url_component)?)
+            .ok_or(ShortCryptError::InvalidPayload)
+    }
+
+    /// Encrypt data with `alphabet` whose length is `2^bits` into a fixed-capacity string without using the heap.
+    #[cfg(feature = "heapless")]
+    fn encrypt_to_heapless<const N: usize>(
+        &self,
+        data: &[u8],
+        alphabet: &[u8],
+        bits: u32,
+    ) -> Result<heapless::String<N>, ShortCryptError> {
+        let mut body = heapless::Vec::<u8, N>::from_slice(data)
+            .map_err(|_| ShortCryptError::BufferTooSmall)?;
+
+        let base = self.encrypt_in_place(&mut body);
+
+        let mut result = heapless::String::new();
+
+        self.write_cipher_bits(base, &body, alphabet, bits, &mut result)
+            .map_err(|_| ShortCryptError::BufferTooSmall)?;
+
+        Ok(result)
+    }
+
+    /// Decrypt a text created by `encrypt_to_heapless` into a fixed-capacity vector without using the heap. The text is validated like `decode_bits`.
+    #[cfg(feature = "heapless")]
+    fn decrypt_heapless<E: BitsEncoding, const N: usize>(
+        &self,
+        text: &[u8],
+        encoding: &E,
+    ) -> Result<heapless::Vec<u8, N>, ShortCryptError> {
+        let (decoding_table, bits, error) = (E::DECODING_TABLE, E::BITS, E::ERROR);
+
+        let alphabet = encoding.alphabet();
+
+        self.check_characters(text, |n| alphabet.contains(&n))?;
+
+        let symbols = || {
+            text.iter()
+                .copied()
+                .filter(|n| !(self.ignore_separators && is_separator(*n) && !alphabet.contains(n)))
+        };
+
+        let (len, sum) = symbols()
+            .fold((0usize, 0u64), |(len, sum), n| (len + 1, sum.wrapping_add(u64::from(n))));
+
+        if len == 0 {
+            return Err(ShortCryptError::InvalidLength);
+        }
+
+        let base_index = self.base_index(sum, len);
+
+        let base = match symbols().nth(base_index).and_then(|n| encoding.symbol_to_base(n)) {
+            Some(base) if base <= 31 => base,
+            _ => return Err(ShortCryptError::InvalidBase),
+        };
+
+        let body_symbols =
+            || symbols().enumerate().filter(|(i, _)| *i != base_index).map(|(_, n)| n);
+
+        // the base symbols may not be a part of the alphabet of the body
+        if !bits::is_valid_body(body_symbols().map(|n| decoding_table[n as usize]), bits, E::STRICT)
+        {
+            return Err(error);
+        }
+
+        let mut result = heapless::Vec::<u8, N>::new();
+
+        result
+            .resize_default((len - 1) * bits as usize / 8)
+            .map_err(|_| ShortCryptError::BufferTooSmall)?;
+
+        let mut i = 0;
+        let mut m = 0;
+        let mut sum = 0u64;
+
+        bits::decode_iter(body_symbols().map(|n| decoding_table[n as usize]), bits, |n| {
+            result[i] = n;
+            i += 1;
+
+            m ^= n;
+            sum = sum.wrapping_add(u64::from(n));
+        });
+
+        self.decrypt_folded(&mut result, base, m, sum)?;
+
+        Ok(result)
+    }
+
+    /// Same as `encrypt_to_url_component`, but the text is stored in a `heapless::String`, so neither the **body** nor the text touches the heap. Returns an error if the capacity `N` is too small.
+    #[cfg(feature = "heapless")]
+    #[inline]
+    pub fn encrypt_to_url_component_heapless<T: ?Sized + AsRef<[u8]>, const N: usize>(
+        &self,
+        data: &T,
+    ) -> Result<heapless::String<N>, ShortCryptError> {
+        self.encrypt_to_heapless(data.as_ref(), URL_COMPONENT_ALPHABET, 6)
+    }
+
+    /// Same as `decrypt_url_component`, but the plaintext is stored in a `heapless::Vec`. Returns an error if the capacity `N` is too small.
+    #[cfg(feature = "heapless")]
+    #[inline]
+    pub fn decrypt_url_component_heapless<S: AsRef<str>, const N: usize>(
+        &self,
+        url_component: S,
+    ) -> Result<heapless::Vec<u8, N>, ShortCryptError> {
+        self.decrypt_heapless(url_component.as_ref().as_bytes(), &UrlComponentEncoding)
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the text is stored in a `heapless::String`, so neither the **body** nor the text touches the heap. Returns an error if the capacity `N` is too small.
+    #[cfg(feature = "heapless")]
+    #[inline]
+    pub fn encrypt_to_qr_code_alphanumeric_heapless<T: ?Sized + AsRef<[u8]>, const N: usize>(
+        &self,
+        data: &T,
+    ) -> Result<heapless::String<N>, ShortCryptError> {
+        self.encrypt_to_heapless(data.as_ref(), BASE32_ALPHABET, 5)
+    }
+
+    /// Same as `decrypt_qr_code_alphanumeric`, but the plaintext is stored in a `heapless::Vec`. Returns an error if the capacity `N` is too small.
+    #[cfg(feature = "heapless")]
+    #[inline]
+    pub fn decrypt_qr_code_alphanumeric_heapless<S: AsRef<str>, const N: usize>(
+        &self,
+        qr_code_alphanumeric: S,
+    ) -> Result<heapless::Vec<u8, N>, ShortCryptError> {
+        self.decrypt_heapless(qr_code_alphanumeric.as_ref().as_bytes(), &QrCodeAlphanumericEncoding)
+    }
+
+    /// Same as `encrypt_to_url_component`, but the text is prefixed by one character which represents `key_id`, so a `KeyedDecoder` can select the key without trying all of them. `key_id` must not be larger than `31`.
+    pub fn encrypt_to_url_component_with_key_id<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        key_id: u8,
+        data: &T,
+    ) -> Result<String, ShortCryptError> {
+        self.encrypt_with_key_id(key_id, data.as_ref(), URL_COMPONENT_ALPHABET, 6)
+    }
+
+    /// Same as `encrypt_to_qr_code_alphanumeric`, but the text is prefixed by one character which represents `key_id`, so a `KeyedDecoder` can select the key without trying all of them. `key_id` must not be larger than `31`.
+    pub fn encrypt_to_qr_code_alphanumeric_with_key_id<T: ?Sized + AsRef<[u8]>>(
+        &self,
+        key_id: u8,
+        data: &T,
+    ) -> Result<String, ShortCryptError> {
+        self.encrypt_with_key_id(key_id, data.as_ref(), BASE32_ALPHABET, 5)
+    }
+
+    fn encrypt_with_key_id(
+        &self,
+        key_id: u8,
+        data: &[u8],
+        alphabet: &[u8],
+        bits: u32,
+    ) -> Result<String, ShortCryptError> {
+        if key_id > key_id::MAX_KEY_ID {
+            return Err(ShortCryptError::OutOfRange);
+        }
+
+        let mut result = String::with_capacity(2 + (data.len() * 8).div_ceil(bits as usize));
+
+        result.push(alphabet[key_id as usize] as char);
+
+        // writing to a `String` never fails
+        let _ = self.write_bits(data, alphabet, bits, &mut result);
+
+        Ok(result)
+    }
+}
+
+/// The 122 bits of a UUID which are not its version or variant.
+const UUID_PAYLOAD_MASK: u128 = (1 << 122) - 1;
+
+/// Spread 122 bits around the version `8` and the variant `10` of RFC 9562.
+#[inline]
+fn uuid_from_payload(payload: u128) -> u128 {
+    let high = payload >> 74;
+    let middle = (payload >> 62) & 0xFFF;
+    let low = payload & ((1 << 62) - 1);
+
+    (high << 80) | (0x8 << 76) | (middle << 64) | (0b10 << 62) | low
+}
+
+/// Collect the 122 bits of a UUID created by `uuid_from_payload`. Returns `None` if its version is not `8` or its variant is not `10`.
+#[inline]
+fn uuid_to_payload(uuid: u128) -> Option<u128> {
+    if (uuid >> 76) & 0xF != 0x8 || (uuid >> 62) & 0b11 != 0b10 {
+        return None;
+    }
+
+    let high = uuid >> 80;
+    let middle = (uuid >> 64) & 0xFFF;
+    let low = uuid & ((1 << 62) - 1);
+
+    Some((high << 74) | (middle << 62) | low)
+}
+
+/// Compute the Luhn check digit of ASCII digits.
+fn luhn_check_digit(digits: &[u8]) -> u8 {
+    let mut sum = 0u32;
+
+    for (i, n) in digits.iter().rev().copied().enumerate() {
+        let d = u32::from(n - b'0');
+
+        sum += if i % 2 == 0 {
+            let d = d * 2;
+
+            if d > 9 {
+                d - 9
+            } else {
+                d
+            }
+        } else {
+            d
+        };
+    }
+
+    b'0' + ((10 - sum % 10) % 10) as u8
+}
+
+#[inline]
+fn dns_label_check_letter(bytes: &[u8]) -> u8 {
+    let mut sum = 0u64;
+
+    for n in bytes.iter().copied() {
+        sum = sum.wrapping_add(u64::from(n));
+    }
+
+    b'a' + (sum % 26) as u8
+}
+
+/// Convert decrypted data to a fixed-size array, checking its length.
+#[inline]
+fn to_array<const N: usize>(data: &[u8]) -> Result<[u8; N], ShortCryptError> {
+    data.try_into().map_err(|_| ShortCryptError::InvalidLength)
+}
+
+/// A `core::fmt::Write` target which writes ASCII texts into a byte slice.
+struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len:    usize,
+}
+
+impl<'a> fmt::Write for SliceWriter<'a> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        let bytes = s.as_bytes();
+        let end = self.len + bytes.len();
+
+        if end > self.buffer.len() {
+            return Err(fmt::Error);
+        }
+
+        self.buffer[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+/// Get the length of each of `lanes` plaintexts stored column by column in `data_len` bytes.
+#[inline]
+fn columns_len(data_len: usize, lanes: usize) -> Result<usize, ShortCryptError> {
+    match data_len.checked_div(lanes) {
+        Some(len) if data_len % lanes == 0 => Ok(len),
+        None if data_len == 0 => Ok(0),
+        _ => Err(ShortCryptError::InvalidLength),
+    }
+}
+
+/// Compute the **base** of a cipher from its plaintext.
+#[inline]
+pub(crate) fn compute_base(data: &[u8]) -> u8 {
+    let mut crc8 = CRCu8::crc8cdma2000();
+
+    crc8.digest(data);
+
+    crc8.get_crc() % 32
+}
+
+#[inline]
+fn is_separator(n: u8) -> bool {
+    matches!(n, b' ' | b'\t' | b'\r' | b'\n' | b'-')
 }
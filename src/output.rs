@@ -0,0 +1,106 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// A container which receives the ASCII text of an encoder, such as `ShortCrypt::encrypt_to_url_component_into`. It is implemented for `String` and `Vec<u8>`, for `heapless::String` and `heapless::Vec<u8, N>` with the `heapless` feature, for `arrayvec::ArrayString` with the `arrayvec` feature, and for `bytes::BytesMut` with the `bytes` feature.
+pub trait OutputBuffer {
+    /// The number of bytes which can still be pushed. Growable containers keep the default `usize::MAX`.
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Reserve space for at least `additional` more bytes, if the container can grow.
+    #[inline]
+    fn reserve(&mut self, _additional: usize) {}
+
+    /// Push ASCII text. The encoders check `remaining_capacity` first, so `s` always fits.
+    fn push_ascii(&mut self, s: &str);
+}
+
+impl OutputBuffer for String {
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        String::reserve(self, additional)
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, s: &str) {
+        self.push_str(s)
+    }
+}
+
+impl OutputBuffer for Vec<u8> {
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional)
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> OutputBuffer for heapless::String<N> {
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        N - self.len()
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, s: &str) {
+        let _ = self.push_str(s);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> OutputBuffer for heapless::Vec<u8, N> {
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        N - self.len()
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, s: &str) {
+        let _ = self.extend_from_slice(s.as_bytes());
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> OutputBuffer for arrayvec::ArrayString<CAP> {
+    #[inline]
+    fn remaining_capacity(&self) -> usize {
+        arrayvec::ArrayString::remaining_capacity(self)
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, s: &str) {
+        let _ = self.try_push_str(s);
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl OutputBuffer for bytes::BytesMut {
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        bytes::BytesMut::reserve(self, additional)
+    }
+
+    #[inline]
+    fn push_ascii(&mut self, s: &str) {
+        self.extend_from_slice(s.as_bytes())
+    }
+}
+
+/// Adapt an `OutputBuffer` to `core::fmt::Write`, so the encoders can write to it.
+pub(crate) struct OutputWriter<'a, B: ?Sized>(pub(crate) &'a mut B);
+
+impl<'a, B: ?Sized + OutputBuffer> fmt::Write for OutputWriter<'a, B> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
+        self.0.push_ascii(s);
+
+        Ok(())
+    }
+}
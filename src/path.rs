@@ -0,0 +1,50 @@
+//! Conversion between paths and bytes used by the path helpers. The bytes of a path are platform-specific: the raw bytes on Unix, the UTF-16 code units in little-endian order on Windows, and UTF-8 elsewhere.
+
+use alloc::vec::Vec;
+use std::path::{Path, PathBuf};
+
+use crate::ShortCryptError;
+
+#[cfg(unix)]
+pub(crate) fn to_bytes(path: &Path) -> Result<Vec<u8>, ShortCryptError> {
+    use std::os::unix::ffi::OsStrExt;
+
+    Ok(path.as_os_str().as_bytes().to_vec())
+}
+
+#[cfg(unix)]
+pub(crate) fn from_bytes(bytes: Vec<u8>) -> Result<PathBuf, ShortCryptError> {
+    use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+    Ok(PathBuf::from(OsString::from_vec(bytes)))
+}
+
+#[cfg(windows)]
+pub(crate) fn to_bytes(path: &Path) -> Result<Vec<u8>, ShortCryptError> {
+    use std::os::windows::ffi::OsStrExt;
+
+    Ok(path.as_os_str().encode_wide().flat_map(u16::to_le_bytes).collect())
+}
+
+#[cfg(windows)]
+pub(crate) fn from_bytes(bytes: Vec<u8>) -> Result<PathBuf, ShortCryptError> {
+    use std::{ffi::OsString, os::windows::ffi::OsStringExt};
+
+    if bytes.len() % 2 != 0 {
+        return Err(ShortCryptError::InvalidPayload);
+    }
+
+    let wide: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+
+    Ok(PathBuf::from(OsString::from_wide(&wide)))
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn to_bytes(path: &Path) -> Result<Vec<u8>, ShortCryptError> {
+    path.to_str().map(|s| s.as_bytes().to_vec()).ok_or(ShortCryptError::Utf8)
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn from_bytes(bytes: Vec<u8>) -> Result<PathBuf, ShortCryptError> {
+    alloc::string::String::from_utf8(bytes).map(PathBuf::from).map_err(|_| ShortCryptError::Utf8)
+}
@@ -0,0 +1,38 @@
+//! Proquint conversion, which turns a 16-bit value into a pronounceable five-letter word.
+
+const CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+const VOWELS: &[u8; 4] = b"aiou";
+
+/// Convert a 16-bit value to a proquint.
+pub(crate) fn encode(value: u16) -> [u8; 5] {
+    let value = value as usize;
+
+    [
+        CONSONANTS[value >> 12],
+        VOWELS[(value >> 10) & 0x3],
+        CONSONANTS[(value >> 6) & 0xF],
+        VOWELS[(value >> 4) & 0x3],
+        CONSONANTS[value & 0xF],
+    ]
+}
+
+/// Convert a proquint back to a 16-bit value.
+pub(crate) fn decode(word: &[u8]) -> Option<u16> {
+    if word.len() != 5 {
+        return None;
+    }
+
+    let mut value = 0u16;
+
+    for (i, n) in word.iter().copied().enumerate() {
+        let n = n.to_ascii_lowercase();
+
+        value = if i % 2 == 0 {
+            (value << 4) | CONSONANTS.iter().position(|c| *c == n)? as u16
+        } else {
+            (value << 2) | VOWELS.iter().position(|c| *c == n)? as u16
+        };
+    }
+
+    Some(value)
+}
@@ -0,0 +1,84 @@
+//! Big-number radix conversion used by the Base58-like text formats.
+//!
+//! Leading zero bytes are kept as leading zero digits (and vice versa), so the conversion is lossless for any byte length.
+
+use alloc::{string::String, vec::Vec};
+
+/// Build a 256-entry table mapping an ASCII byte to its index in `alphabet`. Bytes which are not in the alphabet map to `0xFF`.
+pub(crate) const fn decoding_table(alphabet: &[u8]) -> [u8; 256] {
+    let mut table = [0xFF; 256];
+
+    let mut i = 0;
+
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+
+        i += 1;
+    }
+
+    table
+}
+
+/// Encode `data` with `alphabet` (whose length is the radix) and push the digits to `output`.
+pub(crate) fn encode(data: &[u8], alphabet: &[u8], output: &mut String) {
+    let radix = alphabet.len() as u32;
+
+    let zeros = data.iter().take_while(|n| **n == 0).count();
+
+    // little-endian digits
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 2);
+
+    for n in data[zeros..].iter().copied() {
+        let mut carry = u32::from(n);
+
+        for d in digits.iter_mut() {
+            carry += u32::from(*d) << 8;
+            *d = (carry % radix) as u8;
+            carry /= radix;
+        }
+
+        while carry > 0 {
+            digits.push((carry % radix) as u8);
+            carry /= radix;
+        }
+    }
+
+    output.reserve(zeros + digits.len());
+
+    for _ in 0..zeros {
+        output.push(alphabet[0] as char);
+    }
+
+    for d in digits.iter().rev().copied() {
+        output.push(alphabet[d as usize] as char);
+    }
+}
+
+/// Decode big-endian digit values (each must be smaller than `radix`) back to bytes.
+pub(crate) fn decode(digits: &[u8], radix: u32) -> Vec<u8> {
+    let zeros = digits.iter().take_while(|d| **d == 0).count();
+
+    // little-endian bytes
+    let mut bytes: Vec<u8> = Vec::with_capacity(digits.len());
+
+    for d in digits[zeros..].iter().copied() {
+        let mut carry = u32::from(d);
+
+        for n in bytes.iter_mut() {
+            carry += u32::from(*n) * radix;
+            *n = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut result = vec![0; zeros];
+
+    result.extend(bytes.iter().rev());
+
+    result
+}
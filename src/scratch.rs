@@ -0,0 +1,89 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Debug, Formatter};
+
+use crate::ShortCrypt;
+
+/// Buffers which can be allocated once and passed to `ShortCrypt::encrypt_with_scratch`, `ShortCrypt::encrypt_to_url_component_with_scratch` and `ShortCrypt::encrypt_to_qr_code_alphanumeric_with_scratch` for every request, so a long-running server does not allocate a new **body** or text for each cipher. The buffers grow to fit the longest plaintext seen and are then reused.
+#[derive(Clone, Default)]
+pub struct EncryptScratch {
+    pub(crate) body: Vec<u8>,
+    pub(crate) text: String,
+}
+
+impl Debug for EncryptScratch {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(EncryptScratch, f, self, let .capacity = self.body.capacity());
+    }
+}
+
+impl EncryptScratch {
+    #[inline]
+    pub const fn new() -> EncryptScratch {
+        EncryptScratch {
+            body: Vec::new(), text: String::new()
+        }
+    }
+
+    /// Create buffers which fit plaintexts of up to `plaintext_len` bytes without reallocating.
+    #[inline]
+    pub fn with_capacity(plaintext_len: usize) -> EncryptScratch {
+        EncryptScratch {
+            body: Vec::with_capacity(plaintext_len),
+            text: String::with_capacity(ShortCrypt::qr_code_alphanumeric_len(plaintext_len)),
+        }
+    }
+}
+
+/// A buffer which can be allocated once and passed to `ShortCrypt::decrypt_with_scratch`, `ShortCrypt::decrypt_url_component_with_scratch` and `ShortCrypt::decrypt_qr_code_alphanumeric_with_scratch` for every request, so a long-running server does not allocate a new plaintext for each cipher. The buffer grows to fit the longest plaintext seen and is then reused.
+#[derive(Clone, Default)]
+pub struct DecryptScratch {
+    pub(crate) plaintext: Vec<u8>,
+}
+
+impl Debug for DecryptScratch {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(DecryptScratch, f, self, let .capacity = self.plaintext.capacity());
+    }
+}
+
+impl DecryptScratch {
+    #[inline]
+    pub const fn new() -> DecryptScratch {
+        DecryptScratch {
+            plaintext: Vec::new()
+        }
+    }
+
+    /// Create a buffer which fits plaintexts of up to `plaintext_len` bytes without reallocating.
+    #[inline]
+    pub fn with_capacity(plaintext_len: usize) -> DecryptScratch {
+        DecryptScratch {
+            plaintext: Vec::with_capacity(plaintext_len)
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for EncryptScratch {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.body);
+        zeroize::Zeroize::zeroize(&mut self.text);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for EncryptScratch {}
+
+#[cfg(feature = "zeroize")]
+impl Drop for DecryptScratch {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.plaintext);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for DecryptScratch {}
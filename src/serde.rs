@@ -0,0 +1,182 @@
+//! Field-level encryption for serde. The key is provided by an `EncryptedIdKey` implementation, so structs can expose encrypted fields without changing their handlers.
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use short_crypt::{EncryptedIdKey, ShortCrypt};
+//!
+//! struct ApiKey;
+//!
+//! impl EncryptedIdKey for ApiKey {
+//!     fn short_crypt() -> &'static ShortCrypt {
+//!         static SC: ShortCrypt = ShortCrypt::new_const(b"magickey");
+//!
+//!         &SC
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Article {
+//!     #[serde(
+//!         serialize_with = "short_crypt::serde::url_component::serialize::<ApiKey, _, _>",
+//!         deserialize_with = "short_crypt::serde::url_component::deserialize::<ApiKey, _>"
+//!     )]
+//!     slug: String,
+//! }
+//! ```
+
+use alloc::string::String;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use ::serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{EncryptedId, EncryptedIdKey, ShortCryptError};
+
+/// Serialize a value as `ShortCrypt::encrypt_to_url_component` and deserialize it back to a `String`.
+pub mod url_component {
+    use super::*;
+
+    #[inline]
+    pub fn serialize<K: EncryptedIdKey, T: ?Sized + AsRef<[u8]>, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&K::short_crypt().encrypt_to_url_component(value))
+    }
+
+    #[inline]
+    pub fn deserialize<'de, K: EncryptedIdKey, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<String, D::Error> {
+        let text = String::deserialize(deserializer)?;
+
+        K::short_crypt().decrypt_url_component_to_string(text).map_err(D::Error::custom)
+    }
+}
+
+/// Serialize a value as `ShortCrypt::encrypt_to_qr_code_alphanumeric` and deserialize it back to a `String`.
+pub mod qr_code_alphanumeric {
+    use super::*;
+
+    #[inline]
+    pub fn serialize<K: EncryptedIdKey, T: ?Sized + AsRef<[u8]>, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&K::short_crypt().encrypt_to_qr_code_alphanumeric(value))
+    }
+
+    #[inline]
+    pub fn deserialize<'de, K: EncryptedIdKey, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<String, D::Error> {
+        let text = String::deserialize(deserializer)?;
+
+        K::short_crypt().decrypt_qr_code_alphanumeric_to_string(text).map_err(D::Error::custom)
+    }
+}
+
+/// A string which is serialized as an encrypted URL component with the key of `K`, and decrypted when deserialized.
+pub struct ShortCryptString<K> {
+    string: String,
+    _key:   PhantomData<fn() -> K>,
+}
+
+impl<K> ShortCryptString<K> {
+    #[inline]
+    pub const fn new(string: String) -> ShortCryptString<K> {
+        ShortCryptString {
+            string,
+            _key: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl<K> Debug for ShortCryptString<K> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        Debug::fmt(&self.string, f)
+    }
+}
+
+impl<K> Clone for ShortCryptString<K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.string.clone())
+    }
+}
+
+impl<K> PartialEq for ShortCryptString<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string
+    }
+}
+
+impl<K> Eq for ShortCryptString<K> {}
+
+impl<K> core::hash::Hash for ShortCryptString<K> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.string.hash(state)
+    }
+}
+
+impl<K> Deref for ShortCryptString<K> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl<K> From<String> for ShortCryptString<K> {
+    #[inline]
+    fn from(string: String) -> Self {
+        Self::new(string)
+    }
+}
+
+impl<K: EncryptedIdKey> Serialize for ShortCryptString<K> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        url_component::serialize::<K, _, _>(&self.string, serializer)
+    }
+}
+
+impl<'de, K: EncryptedIdKey> Deserialize<'de> for ShortCryptString<K> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        url_component::deserialize::<K, _>(deserializer).map(Self::new)
+    }
+}
+
+impl<T: Copy + Into<u64> + EncryptedIdKey> Serialize for EncryptedId<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, T: TryFrom<u64> + EncryptedIdKey> Deserialize<'de> for EncryptedId<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+
+        text.parse().map_err(|error: ShortCryptError| D::Error::custom(error))
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Debug, Formatter};
+
+use crc_any::CRCu8;
+
+use crate::{Cipher, ShortCrypt, ShortCryptError};
+
+/// Encrypt data which arrives in chunks, created by `ShortCrypt::encryptor`. The **base** depends on the CRC-8 of the whole plaintext and the permutation covers the whole **body**, so the chunks are buffered until `finalize` is called, while the CRC-8 is updated on the fly.
+pub struct Encryptor<'a> {
+    short_crypt: &'a ShortCrypt,
+    crc8:        CRCu8,
+    buffer:      Vec<u8>,
+}
+
+impl<'a> Debug for Encryptor<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(Encryptor, f, self, .short_crypt, let .len = self.buffer.len());
+    }
+}
+
+impl<'a> Encryptor<'a> {
+    #[inline]
+    pub(crate) fn new(short_crypt: &'a ShortCrypt) -> Encryptor<'a> {
+        Encryptor {
+            short_crypt,
+            crc8: CRCu8::crc8cdma2000(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Feed a chunk of the plaintext.
+    #[inline]
+    pub fn update<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        let data = data.as_ref();
+
+        self.crc8.digest(data);
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// The length of the plaintext fed so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Encrypt all the fed data. The result is the same as `ShortCrypt::encrypt` with the concatenated chunks.
+    #[inline]
+    pub fn finalize(mut self) -> Cipher {
+        let base = self.crc8.get_crc() % 32;
+
+        let mut body = core::mem::take(&mut self.buffer);
+
+        self.short_crypt.encrypt_in_place_with_base(&mut body, base);
+
+        Cipher {
+            base,
+            body,
+        }
+    }
+
+    /// Same as `finalize`, but the cipher is encoded like `ShortCrypt::encrypt_to_url_component`.
+    #[inline]
+    pub fn finalize_to_url_component(self) -> String {
+        let short_crypt = self.short_crypt;
+
+        self.finalize().to_url_component(short_crypt)
+    }
+
+    /// Same as `finalize`, but the cipher is encoded like `ShortCrypt::encrypt_to_qr_code_alphanumeric`.
+    #[inline]
+    pub fn finalize_to_qr_code_alphanumeric(self) -> String {
+        let short_crypt = self.short_crypt;
+
+        self.finalize().to_qr_code_alphanumeric(short_crypt)
+    }
+}
+
+/// Decrypt a cipher or a text which arrives in chunks, created by `ShortCrypt::decryptor`. The chunks are buffered until one of the `finalize` methods is called.
+pub struct Decryptor<'a> {
+    short_crypt: &'a ShortCrypt,
+    buffer:      Vec<u8>,
+}
+
+impl<'a> Debug for Decryptor<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(Decryptor, f, self, .short_crypt, let .len = self.buffer.len());
+    }
+}
+
+impl<'a> Decryptor<'a> {
+    #[inline]
+    pub(crate) fn new(short_crypt: &'a ShortCrypt) -> Decryptor<'a> {
+        Decryptor {
+            short_crypt,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Feed a chunk of the cipher bytes or the text.
+    #[inline]
+    pub fn update<T: ?Sized + AsRef<[u8]>>(&mut self, data: &T) {
+        self.buffer.extend_from_slice(data.as_ref());
+    }
+
+    /// The length of the data fed so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Decrypt the fed data as bytes created by `cipher_to_bytes`. The buffer is reused for the plaintext.
+    pub fn finalize(mut self) -> Result<Vec<u8>, ShortCryptError> {
+        let base = *self.buffer.first().ok_or(ShortCryptError::InvalidLength)?;
+
+        self.short_crypt.decrypt_in_place(base, &mut self.buffer[1..])?;
+
+        self.buffer.remove(0);
+
+        Ok(core::mem::take(&mut self.buffer))
+    }
+
+    /// Decrypt the fed data as a text created by `ShortCrypt::encrypt_to_url_component`.
+    #[inline]
+    pub fn finalize_url_component(self) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = Vec::new();
+
+        let (base, m, sum) =
+            self.short_crypt.decode_url_component_into(&self.buffer, &mut plaintext)?;
+
+        self.short_crypt.decrypt_folded(&mut plaintext, base, m, sum)?;
+
+        Ok(plaintext)
+    }
+
+    /// Decrypt the fed data as a text created by `ShortCrypt::encrypt_to_qr_code_alphanumeric`.
+    #[inline]
+    pub fn finalize_qr_code_alphanumeric(self) -> Result<Vec<u8>, ShortCryptError> {
+        let mut plaintext = Vec::new();
+
+        let (base, m, sum) =
+            self.short_crypt.decode_qr_code_alphanumeric_into(&self.buffer, &mut plaintext)?;
+
+        self.short_crypt.decrypt_folded(&mut plaintext, base, m, sum)?;
+
+        Ok(plaintext)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::io::Write for Encryptor<'a> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::io::Write for Decryptor<'a> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> Drop for Encryptor<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buffer);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> zeroize::ZeroizeOnDrop for Encryptor<'a> {}
+
+#[cfg(feature = "zeroize")]
+impl<'a> Drop for Decryptor<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buffer);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> zeroize::ZeroizeOnDrop for Decryptor<'a> {}
@@ -0,0 +1,65 @@
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::ShortCryptError;
+
+/// The precision of a timestamp encrypted by the `chrono` or `time` helpers. The timestamp is stored as a zigzag LEB128 integer, so a coarser precision produces a shorter cipher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampPrecision {
+    /// Seconds since the Unix epoch.
+    Seconds,
+    /// Milliseconds since the Unix epoch.
+    Milliseconds,
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn from_date_time(
+    date_time: &chrono::DateTime<chrono::Utc>,
+    precision: TimestampPrecision,
+) -> i64 {
+    match precision {
+        TimestampPrecision::Seconds => date_time.timestamp(),
+        TimestampPrecision::Milliseconds => date_time.timestamp_millis(),
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn to_date_time(
+    timestamp: i64,
+    precision: TimestampPrecision,
+) -> Result<chrono::DateTime<chrono::Utc>, ShortCryptError> {
+    match precision {
+        TimestampPrecision::Seconds => chrono::DateTime::from_timestamp(timestamp, 0),
+        TimestampPrecision::Milliseconds => chrono::DateTime::from_timestamp_millis(timestamp),
+    }
+    .ok_or(ShortCryptError::OutOfRange)
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn from_offset_date_time(
+    date_time: &time::OffsetDateTime,
+    precision: TimestampPrecision,
+) -> i64 {
+    match precision {
+        TimestampPrecision::Seconds => date_time.unix_timestamp(),
+        TimestampPrecision::Milliseconds => {
+            date_time.unix_timestamp_nanos().div_euclid(1_000_000) as i64
+        },
+    }
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn to_offset_date_time(
+    timestamp: i64,
+    precision: TimestampPrecision,
+) -> Result<time::OffsetDateTime, ShortCryptError> {
+    match precision {
+        TimestampPrecision::Seconds => time::OffsetDateTime::from_unix_timestamp(timestamp),
+        TimestampPrecision::Milliseconds => {
+            time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(timestamp) * 1_000_000)
+        },
+    }
+    .map_err(|_| ShortCryptError::OutOfRange)
+}
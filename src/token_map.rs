@@ -0,0 +1,107 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::fields;
+
+/// A small map of string keys and values which can be encrypted into one token, e.g. the configuration of a device (`ssid`, `channel`, `id`) carried by a QR code. The entries are ordered by their keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TokenMap {
+    entries: BTreeMap<String, String>,
+}
+
+impl TokenMap {
+    #[inline]
+    pub fn new() -> TokenMap {
+        TokenMap::default()
+    }
+
+    /// Insert an entry. Returns the old value if the key already exists.
+    #[inline]
+    pub fn insert<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> Option<String> {
+        self.entries.insert(key.into(), value.into())
+    }
+
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.entries.remove(key)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries in the order of their keys.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> BTreeMap<String, String> {
+        self.entries
+    }
+
+    /// Pack the entries as length-prefixed fields (key, value, key, value, ...).
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut pairs: Vec<&[u8]> = Vec::with_capacity(self.entries.len() * 2);
+
+        for (k, v) in self.entries.iter() {
+            pairs.push(k.as_bytes());
+            pairs.push(v.as_bytes());
+        }
+
+        fields::pack(&pairs)
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<TokenMap> {
+        let fields = fields::unpack(bytes)?;
+
+        if fields.len() % 2 != 0 {
+            return None;
+        }
+
+        let mut entries = BTreeMap::new();
+        let mut fields = fields.into_iter();
+
+        while let (Some(k), Some(v)) = (fields.next(), fields.next()) {
+            let k = String::from_utf8(k).ok()?;
+            let v = String::from_utf8(v).ok()?;
+
+            if entries.insert(k, v).is_some() {
+                return None;
+            }
+        }
+
+        Some(TokenMap {
+            entries,
+        })
+    }
+}
+
+impl From<BTreeMap<String, String>> for TokenMap {
+    #[inline]
+    fn from(entries: BTreeMap<String, String>) -> TokenMap {
+        TokenMap {
+            entries,
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for TokenMap {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> TokenMap {
+        TokenMap {
+            entries: iter.into_iter().map(|(k, v)| (k.into(), v.into())).collect()
+        }
+    }
+}
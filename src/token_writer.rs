@@ -0,0 +1,84 @@
+use crate::{OutputBuffer, ShortCrypt, ShortCryptError};
+
+/// Compose a text with several encrypted segments (e.g. a URL) in one buffer by chaining `push_*` calls. The buffer can be any `OutputBuffer`. If a push fails, e.g. because a fixed-capacity buffer is full, the following pushes are skipped and `finish` returns the error.
+#[derive(Debug, Clone)]
+pub struct TokenWriter<B> {
+    buffer: B,
+    error:  Option<ShortCryptError>,
+}
+
+impl<B: OutputBuffer> TokenWriter<B> {
+    /// Create a writer which appends to `buffer`.
+    #[inline]
+    pub const fn new(buffer: B) -> TokenWriter<B> {
+        TokenWriter {
+            buffer,
+            error: None,
+        }
+    }
+
+    /// Push an ASCII text as it is. `OutputBuffer` only accepts ASCII, so a literal with another character fails with `ShortCryptError::InvalidCharacter`, whose `index` is the byte index of that character in the literal.
+    #[inline]
+    pub fn push_literal<S: AsRef<str>>(&mut self, literal: S) -> &mut Self {
+        let literal = literal.as_ref();
+
+        if self.error.is_none() {
+            if let Some(index) = literal.bytes().position(|n| !n.is_ascii()) {
+                self.error = Some(ShortCryptError::InvalidCharacter {
+                    index,
+                });
+            } else if self.buffer.remaining_capacity() < literal.len() {
+                self.error = Some(ShortCryptError::BufferTooSmall);
+            } else {
+                self.buffer.reserve(literal.len());
+                self.buffer.push_ascii(literal);
+            }
+        }
+
+        self
+    }
+
+    /// Push `data` encrypted into a URL component, like `ShortCrypt::encrypt_to_url_component`.
+    #[inline]
+    pub fn push_url_component<T: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        short_crypt: &ShortCrypt,
+        data: &T,
+    ) -> &mut Self {
+        if self.error.is_none() {
+            self.error = short_crypt.encrypt_to_url_component_into(data, &mut self.buffer).err();
+        }
+
+        self
+    }
+
+    /// Push `data` encrypted into a QR code alphanumeric text, like `ShortCrypt::encrypt_to_qr_code_alphanumeric`.
+    #[inline]
+    pub fn push_qr_code_alphanumeric<T: ?Sized + AsRef<[u8]>>(
+        &mut self,
+        short_crypt: &ShortCrypt,
+        data: &T,
+    ) -> &mut Self {
+        if self.error.is_none() {
+            self.error =
+                short_crypt.encrypt_to_qr_code_alphanumeric_into(data, &mut self.buffer).err();
+        }
+
+        self
+    }
+
+    /// Get the text composed so far.
+    #[inline]
+    pub fn as_inner(&self) -> &B {
+        &self.buffer
+    }
+
+    /// Get the composed text, or the error of the first failed push.
+    #[inline]
+    pub fn finish(self) -> Result<B, ShortCryptError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.buffer),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! LEB128 (unsigned varint) conversion used by the integer helpers, so that small integers produce short ciphers.
+
+/// Encode `n` into the returned buffer. Returns the buffer and the number of used bytes.
+pub(crate) fn encode(mut n: u64) -> ([u8; 10], usize) {
+    let mut buffer = [0u8; 10];
+    let mut len = 0;
+
+    loop {
+        let b = (n & 0x7F) as u8;
+
+        n >>= 7;
+
+        if n == 0 {
+            buffer[len] = b;
+            len += 1;
+
+            break;
+        }
+
+        buffer[len] = b | 0x80;
+        len += 1;
+    }
+
+    (buffer, len)
+}
+
+/// Decode a minimal LEB128 integer which fits in `u64` at the start of `bytes`. Returns the integer and the number of used bytes.
+pub(crate) fn decode_prefix(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut n = 0u64;
+
+    for (i, b) in bytes.iter().copied().enumerate() {
+        let shift = i as u32 * 7;
+
+        if shift >= 64 || (shift == 63 && b & 0x7F > 1) {
+            return None;
+        }
+
+        n |= u64::from(b & 0x7F) << shift;
+
+        if b & 0x80 == 0 {
+            // a redundant zero byte at the end is not minimal
+            return if b != 0 || i == 0 { Some((n, i + 1)) } else { None };
+        }
+    }
+
+    None
+}
+
+/// Decode bytes created by `encode`. Returns `None` if the bytes are not exactly one minimal LEB128 integer which fits in `u64`.
+pub(crate) fn decode(bytes: &[u8]) -> Option<u64> {
+    match decode_prefix(bytes) {
+        Some((n, len)) if len == bytes.len() => Some(n),
+        _ => None,
+    }
+}
+
+/// Map a signed integer to an unsigned one with zigzag encoding, so integers close to zero (whether negative or not) become small.
+#[inline]
+pub(crate) fn zigzag_encode(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+#[inline]
+pub(crate) fn zigzag_decode(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
@@ -1,10 +1,18 @@
-use short_crypt::ShortCrypt;
+use short_crypt::{
+    Alphabet, Base32HexEncoding, BasePlacement, Cipher, CipherRef, DecryptScratch, EncryptScratch,
+    Format, KeyRing, KeyedDecoder, OutputEncoding, QrCodeAlphanumericEncoding, ShortCrypt,
+    ShortCryptBuilder, ShortCryptError, ShortCryptExt, ShortCryptIteratorExt, TokenWriter,
+    UrlComponentEncoding,
+};
 
 #[test]
 fn test_encrypt() {
     let sc = ShortCrypt::new("magickey");
 
-    assert_eq!((8, [216, 78, 214, 199, 157, 190, 78, 250].to_vec()), sc.encrypt("articles"));
+    assert_eq!(
+        Cipher::new(8, [216, 78, 214, 199, 157, 190, 78, 250].to_vec()).unwrap(),
+        sc.encrypt("articles")
+    );
 }
 
 #[test]
@@ -13,10 +21,76 @@ fn test_decrypt() {
 
     assert_eq!(
         b"articles".to_vec(),
-        sc.decrypt(&(8, vec![216, 78, 214, 199, 157, 190, 78, 250])).unwrap()
+        sc.decrypt(&Cipher::new(8, vec![216, 78, 214, 199, 157, 190, 78, 250]).unwrap()).unwrap()
     );
 }
 
+#[test]
+fn test_new_bytes() {
+    let sc = ShortCrypt::new_bytes(b"magickey");
+
+    assert_eq!("2E87Wx52-Tvo", sc.encrypt_to_url_component("articles"));
+
+    let sc = ShortCrypt::new_bytes([0xFF, 0x00, 0x80]);
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt(&sc.encrypt("articles")).unwrap());
+}
+
+#[test]
+fn test_state() {
+    let sc = ShortCrypt::new("magickey");
+
+    let sc = ShortCrypt::from_state(sc.to_state());
+
+    assert_eq!("2E87Wx52-Tvo", sc.encrypt_to_url_component("articles"));
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(Ok(ShortCrypt::new("magickey")), ShortCrypt::try_new("magickey"));
+    assert_eq!(Err(ShortCryptError::EmptyKey), ShortCrypt::try_new(""));
+    assert_eq!(Ok(ShortCrypt::new("a")), ShortCrypt::try_new("a"));
+
+    assert!(ShortCrypt::try_new_with_min_entropy("magickey", 24).is_ok());
+    assert_eq!(Err(ShortCryptError::WeakKey), ShortCrypt::try_new_with_min_entropy("magickey", 64));
+    assert_eq!(Err(ShortCryptError::WeakKey), ShortCrypt::try_new_with_min_entropy("aaaaaaaa", 1));
+    assert!(ShortCrypt::try_new_with_min_entropy((0..=255).collect::<Vec<u8>>(), 128).is_ok());
+}
+
+#[test]
+fn test_parts() {
+    let sc = ShortCrypt::from_parts([177, 231, 50, 47, 145, 178, 170, 197], 0x52C0000000000000);
+
+    assert_eq!(ShortCrypt::new("magickey"), sc);
+    assert_eq!(([177, 231, 50, 47, 145, 178, 170, 197], 0x52C0000000000000), sc.to_parts());
+    assert_eq!("2E87Wx52-Tvo", sc.encrypt_to_url_component("articles"));
+}
+
+#[test]
+fn test_new_const() {
+    static SC: ShortCrypt = ShortCrypt::new_const(b"magickey");
+
+    assert_eq!(ShortCrypt::new("magickey"), SC);
+    assert_eq!("2E87Wx52-Tvo", SC.encrypt_to_url_component("articles"));
+}
+
+#[test]
+fn test_copy_eq_hash() {
+    use std::collections::HashMap;
+
+    let sc = ShortCrypt::new("magickey");
+    let copied = sc;
+
+    assert_eq!(sc, copied);
+    assert_ne!(sc, ShortCrypt::new("magickey2"));
+
+    let mut tenants = HashMap::new();
+
+    tenants.insert(sc, "tenant");
+
+    assert_eq!(Some(&"tenant"), tenants.get(&ShortCrypt::new("magickey")));
+}
+
 #[test]
 fn test_encrypt_decrypt() {
     let sc = ShortCrypt::new("magickey");
@@ -26,6 +100,191 @@ fn test_encrypt_decrypt() {
     assert_eq!(data.to_vec(), sc.decrypt(&sc.encrypt(data)).unwrap());
 }
 
+#[test]
+fn test_encrypt_decrypt_long() {
+    let sc = ShortCrypt::new("magickey");
+
+    let data = "articles".repeat(5);
+
+    assert_eq!(
+        "6siuXsjG6urOGjV5eyF7Xro3IXl7XxuqujY1eXtfG167IjV5e6sbXrg",
+        sc.encrypt_to_url_component(&data)
+    );
+
+    for len in 0..=data.len() {
+        let data = &data.as_bytes()[..len];
+
+        assert_eq!(data.to_vec(), sc.decrypt(&sc.encrypt(data)).unwrap());
+        assert_eq!(
+            data.to_vec(),
+            sc.decrypt_url_component(sc.encrypt_to_url_component(data)).unwrap()
+        );
+        assert_eq!(
+            data.to_vec(),
+            sc.decrypt_qr_code_alphanumeric(sc.encrypt_to_qr_code_alphanumeric(data)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_encrypt_batch() {
+    let sc = ShortCrypt::new("magickey");
+
+    let items: [&[u8]; 3] = [b"articles", b"", b"magiclen.org"];
+
+    let ciphers = sc.encrypt_batch(&items);
+
+    assert_eq!(3, ciphers.len());
+
+    for (item, cipher) in items.iter().zip(ciphers.iter()) {
+        assert_eq!(item.to_vec(), sc.decrypt(cipher).unwrap());
+    }
+
+    let texts = sc.encrypt_batch_to_url_component(&items);
+
+    assert_eq!("2E87Wx52-Tvo", texts[0]);
+
+    for (item, text) in items.iter().zip(texts.iter()) {
+        assert_eq!(sc.encrypt_to_url_component(item), *text);
+    }
+
+    let texts = sc.encrypt_batch_to_qr_code_alphanumeric(&items);
+
+    assert_eq!("3BHNNR45XZH8PU", texts[0]);
+
+    for (item, text) in items.iter().zip(texts.iter()) {
+        assert_eq!(sc.encrypt_to_qr_code_alphanumeric(item), *text);
+    }
+
+    assert!(sc.encrypt_batch_to_url_component::<&[u8]>(&[]).is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par() {
+    let sc = ShortCrypt::new("magickey");
+
+    let items: Vec<Vec<u8>> = (0u32..10000).map(|i| i.to_be_bytes().to_vec()).collect();
+
+    let texts = sc.par_encrypt_to_url_components(&items);
+
+    assert_eq!(sc.encrypt_batch_to_url_component(&items), texts);
+
+    let decrypted: Vec<Vec<u8>> =
+        sc.par_decrypt_url_components(&texts).into_iter().map(Result::unwrap).collect();
+
+    assert_eq!(items, decrypted);
+
+    let texts = sc.par_encrypt_to_qr_code_alphanumerics(&items);
+
+    assert_eq!(sc.encrypt_batch_to_qr_code_alphanumeric(&items), texts);
+
+    let decrypted: Vec<Vec<u8>> =
+        sc.par_decrypt_qr_code_alphanumerics(&texts).into_iter().map(Result::unwrap).collect();
+
+    assert_eq!(items, decrypted);
+
+    assert!(sc.par_decrypt_url_components(&["2E8*Wx52-Tvo"])[0].is_err());
+}
+
+#[test]
+fn test_columns() {
+    let sc = ShortCrypt::new("magickey");
+
+    let ids: Vec<[u8; 8]> = (0u64..1000).map(|i| (i * 7919).to_be_bytes()).collect();
+
+    let lanes = ids.len();
+
+    let mut data = vec![0; lanes * 8];
+
+    for (k, id) in ids.iter().enumerate() {
+        for (j, n) in id.iter().enumerate() {
+            data[j * lanes + k] = *n;
+        }
+    }
+
+    let mut bases = vec![0; lanes];
+
+    sc.encrypt_columns_in_place(&mut data, &mut bases).unwrap();
+
+    for (k, id) in ids.iter().enumerate() {
+        let body: Vec<u8> = (0..8).map(|j| data[j * lanes + k]).collect();
+
+        assert_eq!(sc.encrypt(id), Cipher::new(bases[k], body).unwrap());
+    }
+
+    sc.decrypt_columns_in_place(&mut data, &bases).unwrap();
+
+    for (k, id) in ids.iter().enumerate() {
+        assert!((0..8).all(|j| data[j * lanes + k] == id[j]));
+    }
+
+    let mut bases = [0; 3];
+
+    sc.encrypt_columns_in_place(&mut [], &mut bases).unwrap();
+
+    assert_eq!([sc.encrypt(b"").base(); 3], bases);
+
+    assert_eq!(
+        Err(ShortCryptError::InvalidLength),
+        sc.encrypt_columns_in_place(&mut [0; 5], &mut bases)
+    );
+    assert_eq!(
+        Err(ShortCryptError::InvalidLength),
+        sc.encrypt_columns_in_place(&mut [0; 5], &mut [])
+    );
+    assert_eq!(
+        Err(ShortCryptError::InvalidBase),
+        sc.decrypt_columns_in_place(&mut [0; 3], &[0, 32, 0])
+    );
+}
+
+#[test]
+fn test_scratch() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut encrypt_scratch = EncryptScratch::new();
+    let mut decrypt_scratch = DecryptScratch::with_capacity(16);
+
+    let cipher = sc.encrypt_with_scratch("articles", &mut encrypt_scratch);
+
+    assert_eq!(8, cipher.base());
+    assert_eq!(&[216, 78, 214, 199, 157, 190, 78, 250], cipher.body());
+    assert_eq!(b"articles", sc.decrypt_with_scratch(cipher, &mut decrypt_scratch).unwrap());
+
+    assert_eq!(
+        "2E87Wx52-Tvo",
+        sc.encrypt_to_url_component_with_scratch("articles", &mut encrypt_scratch)
+    );
+    assert_eq!(
+        "3BHNNR45XZH8PU",
+        sc.encrypt_to_qr_code_alphanumeric_with_scratch("articles", &mut encrypt_scratch)
+    );
+
+    for data in ["magiclen.org", "", "articles"] {
+        let text = sc.encrypt_to_url_component_with_scratch(data, &mut encrypt_scratch);
+
+        assert_eq!(
+            data.as_bytes(),
+            sc.decrypt_url_component_with_scratch(text, &mut decrypt_scratch).unwrap()
+        );
+
+        let text = sc.encrypt_to_qr_code_alphanumeric_with_scratch(data, &mut encrypt_scratch);
+
+        assert_eq!(
+            data.as_bytes(),
+            sc.decrypt_qr_code_alphanumeric_with_scratch(text, &mut decrypt_scratch).unwrap()
+        );
+    }
+
+    assert_eq!(
+        Err(ShortCryptError::InvalidCharacter {
+            index: 3
+        }),
+        sc.decrypt_url_component_with_scratch("2E8*Wx52-Tvo", &mut decrypt_scratch)
+    );
+}
+
 #[test]
 fn test_encrypt_to_url_component() {
     let sc = ShortCrypt::new("magickey");
@@ -64,6 +323,23 @@ fn test_decrypt_url_component_and_push_to_vec() {
     );
 }
 
+#[test]
+fn test_url_component_append() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut url = String::from("https://magiclen.org/");
+
+    sc.encrypt_to_url_component_append("articles", &mut url);
+
+    assert_eq!("https://magiclen.org/2E87Wx52-Tvo", url);
+
+    let mut output = b"https://magiclen.org/".to_vec();
+
+    sc.decrypt_url_component_append("2E87Wx52-Tvo", &mut output).unwrap();
+
+    assert_eq!(b"https://magiclen.org/articles".to_vec(), output);
+}
+
 #[test]
 fn test_encrypt_to_qr_code_alphanumeric() {
     let sc = ShortCrypt::new("magickey");
@@ -101,3 +377,2084 @@ fn test_decrypt_qr_code_alphanumeric_and_push_to_vec() {
         sc.decrypt_qr_code_alphanumeric_and_push_to_vec("3BHNNR45XZH8PU", url).unwrap()
     );
 }
+
+#[test]
+fn test_key_ring() {
+    let old = ShortCrypt::new("magickey");
+
+    let mut key_ring = KeyRing::new(old);
+
+    key_ring.rotate(ShortCrypt::new("newkey"));
+
+    assert_eq!(2, key_ring.len());
+    assert_eq!(&ShortCrypt::new("newkey"), key_ring.primary());
+
+    assert_eq!((1, b"articles".to_vec()), key_ring.decrypt_url_component("2E87Wx52-Tvo").unwrap());
+    assert_eq!(
+        (1, b"articles".to_vec()),
+        key_ring.decrypt_qr_code_alphanumeric("3BHNNR45XZH8PU").unwrap()
+    );
+
+    let url_component = key_ring.encrypt_to_url_component("articles");
+
+    assert_eq!((0, b"articles".to_vec()), key_ring.decrypt_url_component(url_component).unwrap());
+    assert_eq!((0, b"articles".to_vec()), key_ring.decrypt(&key_ring.encrypt("articles")).unwrap());
+
+    assert_eq!(Some(old), key_ring.retire_oldest());
+    assert_eq!(None, key_ring.retire_oldest());
+
+    assert!(key_ring.decrypt_url_component("2E87Wx52-Tvo").is_err());
+}
+
+#[test]
+fn test_key_id() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut decoder = KeyedDecoder::new();
+
+    assert_eq!(None, decoder.insert(3, sc).unwrap());
+    assert_eq!(None, decoder.insert(4, ShortCrypt::new("newkey")).unwrap());
+    assert_eq!(Err(ShortCryptError::OutOfRange), decoder.insert(32, sc));
+
+    assert_eq!("D2E87Wx52-Tvo", sc.encrypt_to_url_component_with_key_id(3, "articles").unwrap());
+    assert_eq!(
+        "D3BHNNR45XZH8PU",
+        sc.encrypt_to_qr_code_alphanumeric_with_key_id(3, "articles").unwrap()
+    );
+    assert_eq!(
+        Err(ShortCryptError::OutOfRange),
+        sc.encrypt_to_url_component_with_key_id(32, "articles")
+    );
+
+    assert_eq!((3, b"articles".to_vec()), decoder.decrypt_url_component("D2E87Wx52-Tvo").unwrap());
+    assert_eq!(
+        (3, b"articles".to_vec()),
+        decoder.decrypt_qr_code_alphanumeric("D3BHNNR45XZH8PU").unwrap()
+    );
+    assert_eq!(Err(ShortCryptError::UnknownKeyId), decoder.decrypt_url_component("A2E87Wx52-Tvo"));
+    assert_eq!(
+        Err(ShortCryptError::InvalidCharacter {
+            index: 0
+        }),
+        decoder.decrypt_url_component("*2E87Wx52-Tvo")
+    );
+    assert_eq!(
+        Err(ShortCryptError::InvalidCharacter {
+            index: 4
+        }),
+        decoder.decrypt_url_component("D2E8*Wx52-Tvo")
+    );
+}
+
+#[test]
+fn test_output_buffer() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut string = String::from("https://magiclen.org/");
+
+    sc.encrypt_to_url_component_into("articles", &mut string).unwrap();
+
+    assert_eq!("https://magiclen.org/2E87Wx52-Tvo", string);
+
+    let mut vec = Vec::new();
+
+    sc.encrypt_to_qr_code_alphanumeric_into("articles", &mut vec).unwrap();
+
+    assert_eq!(b"3BHNNR45XZH8PU".to_vec(), vec);
+
+    #[cfg(feature = "heapless")]
+    {
+        let mut string = heapless::String::<12>::new();
+
+        sc.encrypt_to_url_component_into("articles", &mut string).unwrap();
+
+        assert_eq!("2E87Wx52-Tvo", string.as_str());
+
+        assert_eq!(
+            Err(ShortCryptError::BufferTooSmall),
+            sc.encrypt_to_url_component_into("articles", &mut string)
+        );
+        assert_eq!("2E87Wx52-Tvo", string.as_str());
+    }
+
+    #[cfg(feature = "bytes")]
+    {
+        let data = bytes::Bytes::from_static(b"articles");
+
+        let mut buffer = bytes::BytesMut::from(&b"/"[..]);
+
+        sc.encrypt_to_url_component_into(&data, &mut buffer).unwrap();
+
+        assert_eq!(&b"/2E87Wx52-Tvo"[..], &buffer[..]);
+    }
+
+    #[cfg(feature = "arrayvec")]
+    {
+        let mut string = arrayvec::ArrayString::<13>::new();
+
+        assert_eq!(
+            Err(ShortCryptError::BufferTooSmall),
+            sc.encrypt_to_qr_code_alphanumeric_into("articles", &mut string)
+        );
+        assert!(string.is_empty());
+
+        let mut string = arrayvec::ArrayString::<14>::new();
+
+        sc.encrypt_to_qr_code_alphanumeric_into("articles", &mut string).unwrap();
+
+        assert_eq!("3BHNNR45XZH8PU", string.as_str());
+    }
+}
+
+#[test]
+fn test_token_writer() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut writer = TokenWriter::new(String::new());
+
+    writer
+        .push_literal("https://magiclen.org/")
+        .push_url_component(&sc, "articles")
+        .push_literal("?code=")
+        .push_qr_code_alphanumeric(&sc, "articles");
+
+    assert_eq!("https://magiclen.org/2E87Wx52-Tvo?code=3BHNNR45XZH8PU", writer.finish().unwrap());
+
+    let mut writer = TokenWriter::new(Vec::new());
+
+    writer.push_url_component(&sc, "articles").push_literal("/");
+
+    assert_eq!(b"2E87Wx52-Tvo/".to_vec(), writer.finish().unwrap());
+
+    let mut writer = TokenWriter::new(String::new());
+
+    writer.push_literal("/ü/").push_url_component(&sc, "articles");
+
+    assert_eq!("", writer.as_inner());
+    assert_eq!(
+        Err(ShortCryptError::InvalidCharacter {
+            index: 1
+        }),
+        writer.finish()
+    );
+
+    #[cfg(feature = "heapless")]
+    {
+        let mut writer = TokenWriter::new(heapless::String::<16>::new());
+
+        writer.push_literal("/a/").push_url_component(&sc, "articles").push_literal("/b/");
+
+        assert_eq!("/a/2E87Wx52-Tvo", writer.as_inner().as_str());
+        assert_eq!(Err(ShortCryptError::BufferTooSmall), writer.finish());
+    }
+}
+
+#[test]
+fn test_encoded_len() {
+    let sc = ShortCrypt::new("magickey");
+
+    for len in 0..=64 {
+        let data = vec![0x5Au8; len];
+
+        assert_eq!(sc.encrypt_to_url_component(&data).len(), ShortCrypt::url_component_len(len));
+        assert_eq!(
+            sc.encrypt_to_qr_code_alphanumeric(&data).len(),
+            ShortCrypt::qr_code_alphanumeric_len(len)
+        );
+    }
+
+    const LEN: usize = ShortCrypt::url_component_len(8);
+
+    assert_eq!(12, LEN);
+}
+
+#[test]
+fn test_display() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        "https://magiclen.org/2E87Wx52-Tvo",
+        format!("https://magiclen.org/{}", sc.display_url_component("articles"))
+    );
+    assert_eq!("3BHNNR45XZH8PU", sc.display_qr_code_alphanumeric("articles").to_string());
+}
+
+#[test]
+fn test_push_to_vec() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        b"https://magiclen.org/2E87Wx52-Tvo".to_vec(),
+        sc.encrypt_to_url_component_and_push_to_vec("articles", b"https://magiclen.org/".to_vec())
+    );
+    assert_eq!(
+        b"3BHNNR45XZH8PU".to_vec(),
+        sc.encrypt_to_qr_code_alphanumeric_and_push_to_vec("articles", Vec::new())
+    );
+}
+
+#[test]
+fn test_qr_code_alphanumeric_append() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut url = String::from("https://magiclen.org/");
+
+    sc.encrypt_to_qr_code_alphanumeric_append("articles", &mut url);
+
+    assert_eq!("https://magiclen.org/3BHNNR45XZH8PU", url);
+
+    let mut output = b"https://magiclen.org/".to_vec();
+
+    sc.decrypt_qr_code_alphanumeric_append("3BHNNR45XZH8PU", &mut output).unwrap();
+
+    assert_eq!(b"https://magiclen.org/articles".to_vec(), output);
+}
+
+#[test]
+fn test_encrypt_to_base58() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("dBTaSahCE9t1", sc.encrypt_to_base58("articles"));
+}
+
+#[test]
+fn test_decrypt_base58() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base58("dBTaSahCE9t1").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_base62() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("8IZOw8UQa4D4", sc.encrypt_to_base62("articles"));
+}
+
+#[test]
+fn test_decrypt_base62() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base62("8IZOw8UQa4D4").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_crockford32() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("V178DDHWXQS7FM", sc.encrypt_to_crockford32("articles"));
+}
+
+#[test]
+fn test_decrypt_crockford32() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_crockford32("V178DDHWXQS7FM").unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_crockford32("vl78ddhwxqs7fm").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_hex() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("d8408ed6c79dbe4efa", sc.encrypt_to_hex("articles"));
+}
+
+#[test]
+fn test_decrypt_hex() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_hex("d8408ed6c79dbe4efa").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_qr_code_numeric() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("1558663108513209196282", sc.encrypt_to_qr_code_numeric("articles"));
+}
+
+#[test]
+fn test_decrypt_qr_code_numeric() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_qr_code_numeric("1558663108513209196282").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_z_base_32() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("5b8eppth7z38xw", sc.encrypt_to_z_base_32("articles"));
+}
+
+#[test]
+fn test_decrypt_z_base_32() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_z_base_32("5b8eppth7z38xw").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_base85() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(r"f)M:#<S\@kq", sc.encrypt_to_base85("articles"));
+}
+
+#[test]
+fn test_decrypt_base85() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base85(r"f)M:#<S\@kq").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_base45() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("OFR%6RH.JD8:9", sc.encrypt_to_base45("articles"));
+}
+
+#[test]
+fn test_decrypt_base45() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base45("OFR%6RH.JD8:9").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_custom_alphabet() {
+    let sc = ShortCrypt::new("magickey");
+
+    let alphabet = Alphabet::new("ZYXWVUTSRQPONMLKJIHGFEDCBA!@#$%^").unwrap();
+
+    assert_eq!("@YSMMI#$CASKRF", sc.encrypt_to_custom_alphabet("articles", &alphabet));
+}
+
+#[test]
+fn test_decrypt_custom_alphabet() {
+    let sc = ShortCrypt::new("magickey");
+
+    let alphabet = Alphabet::new("ZYXWVUTSRQPONMLKJIHGFEDCBA!@#$%^").unwrap();
+
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_custom_alphabet("@YSMMI#$CASKRF", &alphabet).unwrap()
+    );
+}
+
+#[test]
+fn test_encrypt_to_emoji() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("📘👎🐈📖📇💝💾👎📺", sc.encrypt_to_emoji("articles"));
+}
+
+#[test]
+fn test_decrypt_emoji() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_emoji("📘👎🐈📖📇💝💾👎📺").unwrap());
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_emoji("📘👎\u{FE0F}🐈📖📇💝💾👎\u{FE0F}📺").unwrap()
+    );
+
+    // a single emoji is the base of an empty plaintext, and U+1F420 represents 32
+    assert_eq!(Err(ShortCryptError::InvalidBase), sc.decrypt_emoji("\u{1F420}"));
+}
+
+#[test]
+fn test_encrypt_to_words() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("success-issue-rapid-derive-top-laptop-animal", sc.encrypt_to_words("articles"));
+}
+
+#[test]
+fn test_decrypt_words() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_words("success-issue-rapid-derive-top-laptop-animal").unwrap()
+    );
+
+    // "laptop" (1000) -> "large" (1001) sets a padding bit of the last body word, and "top" (1831) -> "tooth" (1830) keeps the sum, so "animal" is still the base word
+    assert_eq!(
+        Err(ShortCryptError::InvalidText),
+        sc.decrypt_words("success-issue-rapid-derive-tooth-large-animal")
+    );
+}
+
+#[test]
+fn test_encrypt_to_base64() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("82E7Wx52+Tvo=", sc.encrypt_to_base64("articles"));
+}
+
+#[test]
+fn test_decrypt_base64() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base64("82E7Wx52+Tvo=").unwrap());
+
+    // the text of an empty plaintext is only the base symbol, which must be one of `0`–`9` and `A`–`V`
+    assert_eq!(Vec::<u8>::new(), sc.decrypt_base64(sc.encrypt_to_base64("")).unwrap());
+
+    for text in ["W", "a", "+", "/", "\u{7f}"] {
+        assert_eq!(Err(ShortCryptError::DecodeBase64), sc.decrypt_base64(text));
+    }
+}
+
+#[test]
+fn test_encrypt_to_dns_label() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("u3bihnnr45xzhpu", sc.encrypt_to_dns_label("articles").unwrap());
+}
+
+#[test]
+fn test_decrypt_dns_label() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_dns_label("u3bihnnr45xzhpu").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_proquint() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("todav-tiral-nukuv-hurup-babam", sc.encrypt_to_proquint("articles"));
+}
+
+#[test]
+fn test_decrypt_proquint() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_proquint("todav-tiral-nukuv-hurup-babam").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_bech32() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("tok1mp8ddg3uahe8052qhlq3", sc.encrypt_to_bech32("tok", "articles").unwrap());
+}
+
+#[test]
+fn test_decrypt_bech32() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_bech32("tok", "tok1mp8ddg3uahe8052qhlq3").unwrap());
+    assert!(sc.decrypt_bech32("tok", "tok1mp8ddg3uahe8052qhlq4").is_err());
+}
+
+#[test]
+fn test_encrypt_to_base26() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("GHIQQPRWKRRRPYAI", sc.encrypt_to_base26("articles"));
+}
+
+#[test]
+fn test_decrypt_base26() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base26("GHIQQPRWKRRRPYAI").unwrap());
+}
+
+#[test]
+fn test_cipher_to_bytes() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        vec![8, 216, 78, 214, 199, 157, 190, 78, 250],
+        short_crypt::cipher_to_bytes(&sc.encrypt("articles"))
+    );
+}
+
+#[test]
+fn test_cipher_from_bytes() {
+    let sc = ShortCrypt::new("magickey");
+
+    let cipher =
+        short_crypt::cipher_from_bytes(&[8, 216, 78, 214, 199, 157, 190, 78, 250]).unwrap();
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt(&cipher).unwrap());
+    assert!(short_crypt::cipher_from_bytes(&[32, 0]).is_err());
+    assert_eq!(Err(ShortCryptError::InvalidLength), short_crypt::cipher_from_bytes(&[]));
+}
+
+#[test]
+fn test_cipher() {
+    let sc = ShortCrypt::new("magickey");
+
+    let cipher = sc.encrypt("articles");
+
+    assert_eq!("2E87Wx52-Tvo", cipher.to_url_component(&sc));
+    assert_eq!("3BHNNR45XZH8PU", cipher.to_qr_code_alphanumeric(&sc));
+    assert_eq!((8, vec![216, 78, 214, 199, 157, 190, 78, 250]), cipher.into_parts());
+    assert!(Cipher::try_from((32, vec![0])).is_err());
+}
+
+#[test]
+fn test_cipher_ref() {
+    let sc = ShortCrypt::new("magickey");
+
+    let bytes = [8, 216, 78, 214, 199, 157, 190, 78, 250];
+
+    let cipher = CipherRef::new(bytes[0], &bytes[1..]).unwrap();
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt(cipher).unwrap());
+    assert_eq!(sc.encrypt("articles"), cipher.to_cipher());
+    assert!(CipherRef::new(32, &bytes[1..]).is_err());
+}
+
+#[test]
+fn test_encrypt_into() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut buffer = [0u8; 10];
+
+    assert_eq!(Ok(8), sc.encrypt_into(b"articles", &mut buffer));
+    assert_eq!([216, 78, 214, 199, 157, 190, 78, 250], buffer[..8]);
+    assert_eq!(
+        Err(ShortCryptError::BufferTooSmall),
+        sc.encrypt_into(b"articles", &mut buffer[..7])
+    );
+}
+
+#[test]
+fn test_decrypt_slice() {
+    let sc = ShortCrypt::new("magickey");
+
+    let message = [0xFF, 216, 78, 214, 199, 157, 190, 78, 250];
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_slice(8, &message[1..]).unwrap());
+    assert_eq!(Err(ShortCryptError::InvalidBase), sc.decrypt_slice(32, &message[1..]));
+}
+
+#[test]
+fn test_array() {
+    let sc = ShortCrypt::new("magickey");
+
+    let (base, body) = sc.encrypt_array(b"articles");
+
+    assert_eq!(8, base);
+    assert_eq!([216, 78, 214, 199, 157, 190, 78, 250], body);
+    assert_eq!(b"articles", &sc.decrypt_array(base, &body).unwrap());
+
+    let frame = [7u8; 12];
+
+    let (base, body) = sc.encrypt_array(&frame);
+
+    assert_eq!(frame, sc.decrypt_array(base, &body).unwrap());
+    assert_eq!(Err(ShortCryptError::InvalidBase), sc.decrypt_array(32, &body));
+}
+
+#[test]
+fn test_decrypt_into() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut buffer = [0u8; 10];
+
+    assert_eq!(Ok(8), sc.decrypt_into(8, &[216, 78, 214, 199, 157, 190, 78, 250], &mut buffer));
+    assert_eq!(b"articles", &buffer[..8]);
+    assert_eq!(
+        Err(ShortCryptError::BufferTooSmall),
+        sc.decrypt_into(8, &[216, 78, 214, 199, 157, 190, 78, 250], &mut buffer[..7])
+    );
+    assert_eq!(Err(ShortCryptError::InvalidBase), sc.decrypt_into(32, &[0], &mut buffer));
+}
+
+#[test]
+fn test_encrypt_decrypt_in_place() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut buffer = *b"articles";
+
+    assert_eq!(8, sc.encrypt_in_place(&mut buffer));
+    assert_eq!([216, 78, 214, 199, 157, 190, 78, 250], buffer);
+
+    sc.decrypt_in_place(8, &mut buffer).unwrap();
+
+    assert_eq!(b"articles", &buffer);
+    assert_eq!(Err(ShortCryptError::InvalidBase), sc.decrypt_in_place(32, &mut buffer));
+}
+
+#[test]
+fn test_decrypt_vec() {
+    let sc = ShortCrypt::new("magickey");
+
+    let body = vec![216, 78, 214, 199, 157, 190, 78, 250];
+    let pointer = body.as_ptr();
+
+    let plaintext = sc.decrypt_vec(8, body).unwrap();
+
+    assert_eq!(b"articles".to_vec(), plaintext);
+    assert_eq!(pointer, plaintext.as_ptr());
+    assert!(sc.decrypt_vec(32, vec![0]).is_err());
+}
+
+#[test]
+fn test_encryptor() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut encryptor = sc.encryptor();
+
+    encryptor.update("arti");
+    encryptor.update("cles");
+
+    assert_eq!(8, encryptor.len());
+    assert_eq!(sc.encrypt("articles"), encryptor.finalize());
+
+    let mut encryptor = sc.encryptor();
+
+    encryptor.update("articles");
+
+    assert_eq!("2E87Wx52-Tvo", encryptor.finalize_to_url_component());
+}
+
+#[test]
+fn test_decryptor() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut decryptor = sc.decryptor();
+
+    decryptor.update(&[8, 216, 78, 214]);
+    decryptor.update(&[199, 157, 190, 78, 250]);
+
+    assert_eq!(b"articles".to_vec(), decryptor.finalize().unwrap());
+
+    let mut decryptor = sc.decryptor();
+
+    decryptor.update("2E87W");
+    decryptor.update("x52-Tvo");
+
+    assert_eq!(b"articles".to_vec(), decryptor.finalize_url_component().unwrap());
+    assert!(sc.decryptor().finalize().is_err());
+}
+
+#[test]
+fn test_ext() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("2E87Wx52-Tvo", "articles".encrypt_url_component(&sc));
+    assert_eq!(b"articles".to_vec(), "2E87Wx52-Tvo".decrypt_url_component(&sc).unwrap());
+    assert_eq!("3BHNNR45XZH8PU", b"articles".encrypt_qr_code_alphanumeric(&sc));
+    assert_eq!(b"articles".to_vec(), b"3BHNNR45XZH8PU".decrypt_qr_code_alphanumeric(&sc).unwrap());
+}
+
+#[test]
+fn test_iterator_ext() {
+    let sc = ShortCrypt::new("magickey");
+
+    let url_components: Vec<String> =
+        ["articles", "users"].iter().short_encrypt_url_components(&sc).collect();
+
+    assert_eq!("2E87Wx52-Tvo", url_components[0]);
+
+    let plaintexts: Result<Vec<Vec<u8>>, _> =
+        url_components.iter().short_decrypt_url_components(&sc).collect();
+
+    assert_eq!(vec![b"articles".to_vec(), b"users".to_vec()], plaintexts.unwrap());
+
+    let qr_code_alphanumerics: Vec<String> =
+        ["articles"].iter().short_encrypt_qr_code_alphanumerics(&sc).collect();
+
+    assert_eq!(
+        b"articles".to_vec(),
+        qr_code_alphanumerics
+            .iter()
+            .short_decrypt_qr_code_alphanumerics(&sc)
+            .next()
+            .unwrap()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_encrypt_to_uuid() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("3f0efd52-5047-8431-883a-b2912f32e7b1", sc.encrypt_to_uuid("articles").unwrap());
+
+    // the version is `8` and the variant is `10`, which leaves room for 14 bytes
+    for len in 0..=14 {
+        let uuid = sc.encrypt_to_uuid(&vec![0xFF; len]).unwrap();
+
+        assert_eq!(b'8', uuid.as_bytes()[14]);
+        assert!(b"89ab".contains(&uuid.as_bytes()[19]));
+    }
+
+    assert_eq!(Err(ShortCryptError::DataTooLong), sc.encrypt_to_uuid(&[0; 15]));
+}
+
+#[test]
+fn test_decrypt_uuid() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_uuid("3f0efd52-5047-8431-883a-b2912f32e7b1").unwrap()
+    );
+    assert_eq!(
+        Err(ShortCryptError::InvalidText),
+        sc.decrypt_uuid("3f0efd52-5047-4431-883a-b2912f32e7b1")
+    );
+    assert_eq!(
+        Err(ShortCryptError::InvalidText),
+        sc.decrypt_uuid("3f0efd52-5047-8431-c83a-b2912f32e7b1")
+    );
+}
+
+#[test]
+fn test_decrypt_any() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        (Format::UrlComponent, b"articles".to_vec()),
+        sc.decrypt_any("2E87Wx52-Tvo").unwrap()
+    );
+    assert_eq!(
+        (Format::QrCodeAlphanumeric, b"articles".to_vec()),
+        sc.decrypt_any("3BHNNR45XZH8PU").unwrap()
+    );
+    assert_eq!((Format::Hex, b"articles".to_vec()), sc.decrypt_any("d8408ed6c79dbe4efa").unwrap());
+    assert_eq!(
+        (Format::Uuid, b"articles".to_vec()),
+        sc.decrypt_any("3f0efd52-5047-8431-883a-b2912f32e7b1").unwrap()
+    );
+}
+
+#[test]
+fn test_encrypt_to_code39() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("LUNE7$.SHH8-$", sc.encrypt_to_code39("articles"));
+}
+
+#[test]
+fn test_decrypt_code39() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_code39("LUNE7$.SHH8-$").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_luhn_numeric() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("15586631085132091962820", sc.encrypt_to_luhn_numeric("articles"));
+}
+
+#[test]
+fn test_decrypt_luhn_numeric() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_luhn_numeric("15586631085132091962820").unwrap());
+}
+
+#[test]
+fn test_decrypt_luhn_numeric_typo() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert!(sc.decrypt_luhn_numeric("15586631085132091962830").is_err());
+}
+
+#[test]
+fn test_encrypt_to_serial() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("3BHN-NR45-XZH8-PU", sc.encrypt_to_serial("articles", 4, '-'));
+}
+
+#[test]
+fn test_decrypt_serial() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_serial("3BHN-NR45-XZH8-PU", '-').unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_serial("3BHNN-R45XZ-H8PU", '-').unwrap());
+}
+
+#[test]
+fn test_decrypt_ignore_separators() {
+    let mut sc = ShortCrypt::new("magickey");
+
+    sc.set_ignore_separators(true);
+
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_qr_code_alphanumeric("3BHN NR45\nXZH8-PU").unwrap()
+    );
+    assert_eq!(b"articles".to_vec(), sc.decrypt_url_component("2E87 Wx52-Tvo\r\n").unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base58("dBTa SahC E9t1").unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_hex("d8408ed6-c79dbe4e-fa").unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_crockford32("V178-DDHW-XQS7-FM").unwrap());
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_luhn_numeric("1558 6631 0851 3209 1962 820").unwrap()
+    );
+}
+
+#[test]
+fn test_builder() {
+    let sc = ShortCrypt::builder("magickey").ignore_separators(true).build();
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_url_component("2E87 Wx52-Tvo").unwrap());
+
+    let state = ShortCrypt::new("magickey").to_state();
+
+    let sc = ShortCryptBuilder::from_state(state).build();
+
+    assert_eq!("2E87Wx52-Tvo", sc.encrypt_to_url_component("articles"));
+    assert!(sc.decrypt_url_component("2E87 Wx52-Tvo").is_err());
+}
+
+#[test]
+fn test_builder_format() {
+    let sc = ShortCrypt::builder("magickey").build();
+
+    assert_eq!("2E87Wx52-Tvo", sc.encrypt_to_text("articles").unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_text("2E87Wx52-Tvo").unwrap());
+
+    let sc = ShortCrypt::builder("magickey").format(Format::QrCodeAlphanumeric).build();
+
+    assert_eq!("3BHNNR45XZH8PU", sc.encrypt_to_text("articles").unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_text("3BHNNR45XZH8PU").unwrap());
+
+    let sc = ShortCrypt::builder("magickey").format(Format::Uuid).build();
+
+    assert_eq!(Err(ShortCryptError::DataTooLong), sc.encrypt_to_text(&[0u8; 15]));
+
+    for format in [Format::QrCodeNumeric, Format::Hex, Format::Emoji] {
+        let sc = ShortCrypt::builder("magickey").format(format).build();
+
+        let text = sc.encrypt_to_text("articles").unwrap();
+
+        assert_eq!(b"articles".to_vec(), sc.decrypt_text(&text).unwrap());
+        assert_eq!(Ok((format, b"articles".to_vec())), sc.decrypt_any(&text));
+    }
+}
+
+#[test]
+fn test_builder_base_placement() {
+    let keyed = ShortCrypt::new("magickey");
+    let start = ShortCrypt::builder("magickey").base_placement(BasePlacement::Start).build();
+    let end = ShortCrypt::builder("magickey").base_placement(BasePlacement::End).build();
+
+    for plaintext in ["", "a", "articles", "The quick brown fox jumps over the lazy dog."] {
+        let start_text = start.encrypt_to_url_component(plaintext);
+        let end_text = end.encrypt_to_url_component(plaintext);
+
+        assert_eq!(start_text[1..], end_text[..(end_text.len() - 1)]);
+        assert_eq!(start_text[..1], end_text[(end_text.len() - 1)..]);
+
+        assert_eq!(plaintext.as_bytes(), start.decrypt_url_component(&start_text).unwrap());
+        assert_eq!(plaintext.as_bytes(), end.decrypt_url_component(&end_text).unwrap());
+
+        let text = start.encrypt_to_qr_code_alphanumeric(plaintext);
+
+        assert_eq!(plaintext.as_bytes(), start.decrypt_qr_code_alphanumeric(&text).unwrap());
+    }
+
+    assert_eq!("82E7Wx52-Tvo", start.encrypt_to_url_component("articles"));
+    assert_ne!(
+        keyed.encrypt_to_url_component("articles"),
+        start.encrypt_to_url_component("articles")
+    );
+}
+
+#[test]
+fn test_builder_padding() {
+    let sc = ShortCrypt::builder("magickey").padding(false).build();
+
+    assert_eq!("82E7Wx52+Tvo", sc.encrypt_to_base64("articles"));
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base64("82E7Wx52+Tvo").unwrap());
+    assert_eq!(Err(ShortCryptError::DecodeBase64), sc.decrypt_base64("82E7Wx52+Tvo="));
+
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(Err(ShortCryptError::DecodeBase64), sc.decrypt_base64("82E7Wx52+Tvo"));
+}
+
+#[test]
+fn test_builder_checksum() {
+    let sc = ShortCrypt::builder("magickey").checksum(true).build();
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_url_component("2E87Wx52-Tvo").unwrap());
+
+    let cipher = sc.encrypt("articles");
+    let wrong_base = (cipher.base() + 1) % 32;
+
+    assert!(ShortCrypt::new("magickey").decrypt_slice(wrong_base, cipher.body()).is_ok());
+    assert_eq!(Err(ShortCryptError::InvalidChecksum), sc.decrypt_slice(wrong_base, cipher.body()));
+
+    let mut output = b"prefix".to_vec();
+    let text = sc.encrypt_to_url_component("articles").replace('W', "X");
+
+    assert_eq!(
+        Err(ShortCryptError::InvalidChecksum),
+        sc.decrypt_url_component_append(&text, &mut output)
+    );
+    assert_eq!(b"prefix", output.as_slice());
+
+    let mut data = b"aabbcc".to_vec();
+    let mut bases = [0; 2];
+
+    sc.encrypt_columns_in_place(&mut data, &mut bases).unwrap();
+
+    let encrypted = data.clone();
+
+    bases[1] = (bases[1] + 1) % 32;
+
+    assert_eq!(
+        Err(ShortCryptError::InvalidChecksum),
+        sc.decrypt_columns_in_place(&mut data, &bases)
+    );
+    assert_eq!(encrypted, data);
+}
+
+/// The QR code alphanumeric encoding in lowercase.
+struct LowercaseEncoding;
+
+impl OutputEncoding for LowercaseEncoding {
+    fn alphabet(&self) -> &[u8] {
+        b"0123456789abcdefghijklmnopqrstuvwxyz"
+    }
+
+    fn encode(&self, data: &[u8], output: &mut String) {
+        let start = output.len();
+
+        QrCodeAlphanumericEncoding.encode(data, output);
+
+        output[start..].make_ascii_lowercase();
+    }
+
+    fn decode(&self, text: &[u8]) -> Option<Vec<u8>> {
+        QrCodeAlphanumericEncoding.decode(&text.to_ascii_uppercase())
+    }
+
+    fn base_to_symbol(&self, base: u8) -> u8 {
+        QrCodeAlphanumericEncoding.base_to_symbol(base).to_ascii_lowercase()
+    }
+
+    fn symbol_to_base(&self, symbol: u8) -> Option<u8> {
+        QrCodeAlphanumericEncoding.symbol_to_base(symbol.to_ascii_uppercase())
+    }
+}
+
+#[test]
+fn test_encrypt_to() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("2E87Wx52-Tvo", sc.encrypt_to("articles", &UrlComponentEncoding));
+    assert_eq!("3BHNNR45XZH8PU", sc.encrypt_to("articles", &QrCodeAlphanumericEncoding));
+    assert_eq!("3bhnnr45x8zhpu", sc.encrypt_to("articles", &LowercaseEncoding));
+}
+
+#[test]
+fn test_decrypt_from() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_from("2E87Wx52-Tvo", &UrlComponentEncoding).unwrap()
+    );
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_from("3BHNNR45XZH8PU", &QrCodeAlphanumericEncoding).unwrap()
+    );
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_from("3bhnnr45x8zhpu", &LowercaseEncoding).unwrap()
+    );
+}
+
+#[test]
+fn test_encrypt_to_base36() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("3AF47Q8QPSUA62", sc.encrypt_to_base36("articles"));
+}
+
+#[test]
+fn test_decrypt_base36() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base36("3AF47Q8QPSUA62").unwrap());
+}
+
+#[test]
+fn test_decrypt_base36_lowercase() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base36("3af47q8qpsua62").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_base32_lowercase() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("3bhnnr45xzh8pu", sc.encrypt_to_base32_lowercase("articles"));
+}
+
+#[test]
+fn test_decrypt_base32_lowercase() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base32_lowercase("3bhnnr45xzh8pu").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_base32hex() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("R17DDHSTNP78FK", sc.encrypt_to_base32hex("articles"));
+}
+
+#[test]
+fn test_decrypt_base32hex() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_base32hex("R17DDHSTNP78FK").unwrap());
+}
+
+#[test]
+fn test_encrypt_to_base32hex_encoding() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("R17DDHSTNP78FK", sc.encrypt_to("articles", &Base32HexEncoding));
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_from("R17DDHSTNP78FK", &Base32HexEncoding).unwrap()
+    );
+}
+
+#[test]
+fn test_encrypt_to_url_component_writer() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut s = String::from("https://magiclen.org/");
+
+    sc.encrypt_to_url_component_writer("articles", &mut s).unwrap();
+
+    assert_eq!("https://magiclen.org/2E87Wx52-Tvo", s);
+
+    for plaintext in ["", "a", "ab", "abc", "abcd", "abcdefghijklmnopqrstuvwxyz"] {
+        let mut s = String::new();
+
+        sc.encrypt_to_url_component_writer(plaintext, &mut s).unwrap();
+
+        assert_eq!(sc.encrypt_to_url_component(plaintext), s);
+    }
+}
+
+#[test]
+fn test_encrypt_to_qr_code_alphanumeric_writer() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut s = String::new();
+
+    sc.encrypt_to_qr_code_alphanumeric_writer("articles", &mut s).unwrap();
+
+    assert_eq!("3BHNNR45XZH8PU", s);
+
+    for plaintext in ["", "a", "ab", "abc", "abcd", "abcdefghijklmnopqrstuvwxyz"] {
+        let mut s = String::new();
+
+        sc.encrypt_to_qr_code_alphanumeric_writer(plaintext, &mut s).unwrap();
+
+        assert_eq!(sc.encrypt_to_qr_code_alphanumeric(plaintext), s);
+    }
+}
+
+#[test]
+fn test_encrypt_to_url_component_buf() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut buffer = [0u8; 16];
+
+    let len = sc.encrypt_to_url_component_buf("articles", &mut buffer).unwrap();
+
+    assert_eq!(b"2E87Wx52-Tvo", &buffer[..len]);
+
+    assert!(sc.encrypt_to_url_component_buf("articles", &mut buffer[..11]).is_err());
+}
+
+#[test]
+fn test_encrypt_to_qr_code_alphanumeric_buf() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mut buffer = [0u8; 16];
+
+    let len = sc.encrypt_to_qr_code_alphanumeric_buf("articles", &mut buffer).unwrap();
+
+    assert_eq!(b"3BHNNR45XZH8PU", &buffer[..len]);
+
+    assert!(sc.encrypt_to_qr_code_alphanumeric_buf("articles", &mut buffer[..13]).is_err());
+}
+
+#[test]
+fn test_encrypt_to_chars() {
+    let sc = ShortCrypt::new("magickey");
+
+    let chars = sc.encrypt_to_url_component_chars("articles");
+
+    assert_eq!(12, chars.len());
+    assert_eq!("2E87Wx52-Tvo", chars.collect::<String>());
+
+    assert_eq!(
+        "3BHNNR45XZH8PU",
+        sc.encrypt_to_qr_code_alphanumeric_chars("articles").collect::<String>()
+    );
+
+    let alphabet = Alphabet::new("ZYXWVUTSRQPONMLKJIHGFEDCBA!@#$%^").unwrap();
+
+    assert_eq!(
+        "@YSMMI#$CASKRF",
+        sc.encrypt_to_custom_alphabet_chars("articles", &alphabet).collect::<String>()
+    );
+
+    for plaintext in ["", "a", "ab", "abc", "abcd", "abcdefghijklmnopqrstuvwxyz"] {
+        assert_eq!(
+            sc.encrypt_to_url_component(plaintext),
+            sc.encrypt_to_url_component_chars(plaintext).collect::<String>()
+        );
+        assert_eq!(
+            sc.encrypt_to_qr_code_alphanumeric(plaintext),
+            sc.encrypt_to_qr_code_alphanumeric_chars(plaintext).collect::<String>()
+        );
+    }
+}
+
+#[test]
+fn test_encrypt_to_qr_code_kanji() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        vec![155, 141, 157, 102, 153, 250, 155, 199, 143, 105, 136, 199, 150, 86],
+        sc.encrypt_to_qr_code_kanji("articles")
+    );
+}
+
+#[test]
+fn test_decrypt_qr_code_kanji() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        b"articles".to_vec(),
+        sc.decrypt_qr_code_kanji(&[
+            155, 141, 157, 102, 153, 250, 155, 199, 143, 105, 136, 199, 150, 86
+        ])
+        .unwrap()
+    );
+
+    // the last character sets the padding byte to 1, and the first one keeps the sum, so the base character does not move
+    assert_eq!(
+        Err(ShortCryptError::InvalidText),
+        sc.decrypt_qr_code_kanji(&[
+            155, 140, 157, 102, 153, 250, 155, 199, 143, 105, 136, 199, 150, 87
+        ])
+    );
+}
+
+#[test]
+fn test_encrypt_u64() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        Cipher::new(4, vec![227, 174, 54, 235, 149, 43, 181, 182]).unwrap(),
+        sc.encrypt_u64(42)
+    );
+    assert_eq!("464265Urt4bY", sc.encrypt_u64_to_url_component(42));
+    assert_eq!("4OXDN24VFO423M", sc.encrypt_u64_to_qr_code_alphanumeric(42));
+}
+
+#[test]
+fn test_decrypt_u64() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        42,
+        sc.decrypt_u64(&Cipher::new(4, vec![227, 174, 54, 235, 149, 43, 181, 182]).unwrap())
+            .unwrap()
+    );
+    assert_eq!(42, sc.decrypt_url_component_to_u64("464265Urt4bY").unwrap());
+    assert_eq!(42, sc.decrypt_qr_code_alphanumeric_to_u64("4OXDN24VFO423M").unwrap());
+
+    assert!(sc.decrypt_url_component_to_u64("2E87Wx52-Tvo").is_ok());
+    assert!(sc.decrypt_url_component_to_u64(sc.encrypt_to_url_component("article")).is_err());
+}
+
+#[test]
+fn test_u128() {
+    let sc = ShortCrypt::new("magickey");
+
+    let n = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+
+    assert_eq!(n, sc.decrypt_u128(&sc.encrypt_u128(n)).unwrap());
+    assert_eq!(n, sc.decrypt_url_component_to_u128(sc.encrypt_u128_to_url_component(n)).unwrap());
+    assert_eq!(
+        n,
+        sc.decrypt_qr_code_alphanumeric_to_u128(sc.encrypt_u128_to_qr_code_alphanumeric(n))
+            .unwrap()
+    );
+
+    assert!(sc.decrypt_url_component_to_u128(sc.encrypt_u64_to_url_component(42)).is_err());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid() {
+    let sc = ShortCrypt::new("magickey");
+
+    let uuid = uuid::Uuid::from_u128(0x67E5_5044_10B1_426F_9247_BB68_0E5F_E0C8);
+
+    assert_eq!(uuid, sc.decrypt_to_uuid(&sc.encrypt_uuid(&uuid)).unwrap());
+    assert_eq!(
+        uuid,
+        sc.decrypt_url_component_to_uuid(sc.encrypt_uuid_to_url_component(&uuid)).unwrap()
+    );
+    assert_eq!(
+        uuid,
+        sc.decrypt_qr_code_alphanumeric_to_uuid(sc.encrypt_uuid_to_qr_code_alphanumeric(&uuid))
+            .unwrap()
+    );
+    assert_eq!(
+        uuid,
+        sc.decrypt_from_to_uuid(sc.encrypt_uuid_to(&uuid, &Base32HexEncoding), &Base32HexEncoding)
+            .unwrap()
+    );
+
+    assert!(sc.decrypt_url_component_to_uuid("2E87Wx52-Tvo").is_err());
+}
+
+#[test]
+fn test_u64_varint() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(1, sc.encrypt_u64_varint(42).body().len());
+    assert_eq!("kAB", sc.encrypt_u64_varint_to_url_component(42));
+    assert_eq!("SAB", sc.encrypt_u64_varint_to_qr_code_alphanumeric(42));
+
+    assert_eq!(42, sc.decrypt_url_component_to_u64_varint("kAB").unwrap());
+    assert_eq!(42, sc.decrypt_qr_code_alphanumeric_to_u64_varint("SAB").unwrap());
+
+    for n in [0, 127, 128, 16384, u64::MAX] {
+        assert_eq!(n, sc.decrypt_u64_varint(&sc.encrypt_u64_varint(n)).unwrap());
+    }
+
+    assert!(sc.decrypt_u64_varint(&sc.encrypt(&[0x80, 0x00])).is_err());
+}
+
+#[test]
+fn test_ip() {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    let sc = ShortCrypt::new("magickey");
+
+    let ipv4 = Ipv4Addr::new(192, 168, 1, 42);
+    let ipv6 = Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1);
+
+    assert_eq!(ipv4, sc.decrypt_ipv4(&sc.encrypt_ipv4(&ipv4)).unwrap());
+    assert_eq!(
+        ipv4,
+        sc.decrypt_url_component_to_ipv4(sc.encrypt_ipv4_to_url_component(&ipv4)).unwrap()
+    );
+    assert_eq!(
+        ipv4,
+        sc.decrypt_qr_code_alphanumeric_to_ipv4(sc.encrypt_ipv4_to_qr_code_alphanumeric(&ipv4))
+            .unwrap()
+    );
+
+    assert_eq!(ipv6, sc.decrypt_ipv6(&sc.encrypt_ipv6(&ipv6)).unwrap());
+    assert_eq!(
+        ipv6,
+        sc.decrypt_url_component_to_ipv6(sc.encrypt_ipv6_to_url_component(&ipv6)).unwrap()
+    );
+    assert_eq!(
+        ipv6,
+        sc.decrypt_qr_code_alphanumeric_to_ipv6(sc.encrypt_ipv6_to_qr_code_alphanumeric(&ipv6))
+            .unwrap()
+    );
+
+    assert!(sc.decrypt_url_component_to_ipv6(sc.encrypt_ipv4_to_url_component(&ipv4)).is_err());
+}
+
+#[test]
+fn test_decrypt_to_string() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!("articles", sc.decrypt_url_component_to_string("2E87Wx52-Tvo").unwrap());
+    assert_eq!("articles", sc.decrypt_qr_code_alphanumeric_to_string("3BHNNR45XZH8PU").unwrap());
+
+    assert!(sc
+        .decrypt_url_component_to_string(sc.encrypt_to_url_component(&[0xFF, 0xFE]))
+        .is_err());
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless() {
+    let sc = ShortCrypt::new("magickey");
+
+    let url_component = sc.encrypt_to_url_component_heapless::<_, 16>("articles").unwrap();
+    let qr_code_alphanumeric =
+        sc.encrypt_to_qr_code_alphanumeric_heapless::<_, 16>("articles").unwrap();
+
+    assert_eq!("2E87Wx52-Tvo", url_component.as_str());
+    assert_eq!("3BHNNR45XZH8PU", qr_code_alphanumeric.as_str());
+    assert_eq!(
+        b"articles",
+        sc.decrypt_url_component_heapless::<_, 8>(&url_component).unwrap().as_slice()
+    );
+    assert_eq!(
+        b"articles",
+        sc.decrypt_qr_code_alphanumeric_heapless::<_, 8>(&qr_code_alphanumeric).unwrap().as_slice()
+    );
+    assert!(sc.encrypt_to_url_component_heapless::<_, 8>("articles").is_err());
+    assert!(sc.decrypt_url_component_heapless::<_, 7>(&url_component).is_err());
+
+    // the unused bits of the last symbol must be zero, like `decrypt_url_component`
+    assert_eq!(Err(ShortCryptError::DecodeBase64), sc.decrypt_url_component("2E87Wx52-TvC"));
+    assert_eq!(
+        Err(ShortCryptError::DecodeBase64),
+        sc.decrypt_url_component_heapless::<_, 32>("2E87Wx52-TvC").map(|v| v.to_vec())
+    );
+
+    for c in "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_".chars() {
+        let text = format!("2E87Wx52-Tv{c}");
+
+        assert_eq!(
+            sc.decrypt_url_component(&text),
+            sc.decrypt_url_component_heapless::<_, 32>(&text).map(|v| v.to_vec())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_cipher_serde() {
+    let sc = ShortCrypt::new("magickey");
+
+    let cipher = sc.encrypt("articles");
+
+    let bytes = postcard::to_allocvec(&cipher).unwrap();
+
+    assert_eq!(cipher, postcard::from_bytes(&bytes).unwrap());
+    assert!(postcard::from_bytes::<Cipher>(&postcard::to_allocvec(&(32u8, vec![0u8])).unwrap())
+        .is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_fields() {
+    use serde::{Deserialize, Serialize};
+    use short_crypt::{serde::ShortCryptString, EncryptedId, EncryptedIdKey};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct ApiKey;
+
+    impl EncryptedIdKey for ApiKey {
+        fn short_crypt() -> &'static ShortCrypt {
+            static SC: ShortCrypt = ShortCrypt::new_const(b"magickey");
+
+            &SC
+        }
+    }
+
+    impl From<ApiKey> for u64 {
+        fn from(_: ApiKey) -> u64 {
+            0
+        }
+    }
+
+    impl TryFrom<u64> for ApiKey {
+        type Error = ();
+
+        fn try_from(id: u64) -> Result<ApiKey, ()> {
+            if id == 0 {
+                Ok(ApiKey)
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Article {
+        #[serde(
+            serialize_with = "short_crypt::serde::url_component::serialize::<ApiKey, _, _>",
+            deserialize_with = "short_crypt::serde::url_component::deserialize::<ApiKey, _>"
+        )]
+        slug:   String,
+        title:  ShortCryptString<ApiKey>,
+        author: EncryptedId<ApiKey>,
+    }
+
+    let article = Article {
+        slug:   String::from("articles"),
+        title:  ShortCryptString::new(String::from("articles")),
+        author: EncryptedId(ApiKey),
+    };
+
+    let bytes = postcard::to_allocvec(&article).unwrap();
+
+    assert_eq!(
+        postcard::to_allocvec(&("2E87Wx52-Tvo", "2E87Wx52-Tvo", article.author.to_string()))
+            .unwrap(),
+        bytes
+    );
+    assert_eq!(article, postcard::from_bytes(&bytes).unwrap());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() {
+    use zeroize::Zeroize;
+
+    let mut sc = ShortCrypt::new("magickey");
+
+    sc.zeroize();
+
+    assert_eq!([0; 16], sc.to_state());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_value() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payload {
+        user_id: u64,
+        role:    String,
+    }
+
+    let sc = ShortCrypt::new("magickey");
+
+    let payload = Payload {
+        user_id: 42, role: "admin".to_string()
+    };
+
+    assert_eq!(payload, sc.decrypt_value::<Payload>(&sc.encrypt_value(&payload).unwrap()).unwrap());
+    assert_eq!(
+        payload,
+        sc.decrypt_url_component_to_value::<Payload, _>(
+            sc.encrypt_value_to_url_component(&payload).unwrap()
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        payload,
+        sc.decrypt_qr_code_alphanumeric_to_value::<Payload, _>(
+            sc.encrypt_value_to_qr_code_alphanumeric(&payload).unwrap()
+        )
+        .unwrap()
+    );
+
+    assert!(sc.decrypt_url_component_to_value::<Payload, _>("2E87Wx52-Tvo").is_err());
+}
+
+#[test]
+fn test_timed() {
+    let sc = ShortCrypt::new("magickey");
+
+    let now = 1_700_000_000;
+
+    let token = sc.encrypt_with_ttl("articles", now, 3600);
+
+    assert_eq!(b"articles".to_vec(), sc.decrypt_timed(&token, now).unwrap());
+    assert_eq!(b"articles".to_vec(), sc.decrypt_timed(&token, now + 3599).unwrap());
+    assert_eq!(Err(ShortCryptError::Expired), sc.decrypt_timed(&token, now + 3600));
+
+    // the expiry prefix is a truncated varint
+    assert_eq!(
+        Err(ShortCryptError::InvalidPayload),
+        sc.decrypt_timed(sc.encrypt_to_url_component(&[0x80, 0x80]), now)
+    );
+}
+
+#[test]
+fn test_cursor() {
+    use short_crypt::cursor::Cursor;
+
+    let sc = ShortCrypt::new("magickey");
+
+    let cursor = Cursor::new(40, 20).with_sort_key("created_at");
+
+    assert_eq!(cursor, sc.decrypt_cursor(sc.encrypt_cursor(&cursor)).unwrap());
+
+    let next = cursor.next_page();
+
+    assert_eq!(60, next.offset);
+    assert_eq!(next, sc.decrypt_cursor(sc.encrypt_cursor(&next)).unwrap());
+
+    assert!(sc.decrypt_cursor(sc.encrypt_to_url_component(&[0x80])).is_err());
+}
+
+#[test]
+fn test_fields() {
+    let sc = ShortCrypt::new("magickey");
+
+    let fields: [&[u8]; 4] = [b"tenant", b"", &[0, 1, 2, 255], b"flags-and-more"];
+
+    let expected: Vec<Vec<u8>> = fields.iter().map(|f| f.to_vec()).collect();
+
+    assert_eq!(expected, sc.decrypt_fields(&sc.encrypt_fields(&fields)).unwrap());
+    assert_eq!(
+        expected,
+        sc.decrypt_url_component_to_fields(sc.encrypt_fields_to_url_component(&fields)).unwrap()
+    );
+    assert_eq!(
+        expected,
+        sc.decrypt_qr_code_alphanumeric_to_fields(
+            sc.encrypt_fields_to_qr_code_alphanumeric(&fields)
+        )
+        .unwrap()
+    );
+
+    assert!(sc.decrypt_url_component_to_fields(sc.encrypt_to_url_component(&[5, 1, 2])).is_err());
+}
+
+#[test]
+fn test_encrypted_id() {
+    use std::sync::OnceLock;
+
+    use short_crypt::{EncryptedId, EncryptedIdKey};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct UserId(u32);
+
+    impl From<UserId> for u64 {
+        fn from(id: UserId) -> u64 {
+            u64::from(id.0)
+        }
+    }
+
+    impl TryFrom<u64> for UserId {
+        type Error = std::num::TryFromIntError;
+
+        fn try_from(id: u64) -> Result<UserId, Self::Error> {
+            u32::try_from(id).map(UserId)
+        }
+    }
+
+    impl EncryptedIdKey for UserId {
+        fn short_crypt() -> &'static ShortCrypt {
+            static SC: OnceLock<ShortCrypt> = OnceLock::new();
+
+            SC.get_or_init(|| ShortCrypt::new("magickey"))
+        }
+    }
+
+    let id = EncryptedId(UserId(42));
+
+    let s = id.to_string();
+
+    assert_eq!("kAB", s);
+    assert_eq!(id, s.parse().unwrap());
+
+    let too_large = ShortCrypt::new("magickey").encrypt_u64_varint_to_url_component(u64::MAX);
+
+    assert!(too_large.parse::<EncryptedId<UserId>>().is_err());
+}
+
+#[test]
+fn test_i64() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(1, sc.encrypt_i64(-42).body().len());
+
+    for n in [0, 1, -1, 63, -64, 64, i64::MAX, i64::MIN] {
+        assert_eq!(n, sc.decrypt_i64(&sc.encrypt_i64(n)).unwrap());
+        assert_eq!(n, sc.decrypt_url_component_to_i64(sc.encrypt_i64_to_url_component(n)).unwrap());
+        assert_eq!(
+            n,
+            sc.decrypt_qr_code_alphanumeric_to_i64(sc.encrypt_i64_to_qr_code_alphanumeric(n))
+                .unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_date_time() {
+    use short_crypt::TimestampPrecision;
+
+    let sc = ShortCrypt::new("magickey");
+
+    let date_time = chrono::DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+
+    assert_eq!(
+        date_time,
+        sc.decrypt_url_component_to_date_time(
+            sc.encrypt_date_time_to_url_component(&date_time, TimestampPrecision::Milliseconds),
+            TimestampPrecision::Milliseconds
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+        sc.decrypt_date_time(
+            &sc.encrypt_date_time(&date_time, TimestampPrecision::Seconds),
+            TimestampPrecision::Seconds
+        )
+        .unwrap()
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_offset_date_time() {
+    use short_crypt::TimestampPrecision;
+
+    let sc = ShortCrypt::new("magickey");
+
+    let date_time =
+        time::OffsetDateTime::from_unix_timestamp_nanos(-1_700_000_000_123_000_000).unwrap();
+
+    assert_eq!(
+        date_time,
+        sc.decrypt_url_component_to_offset_date_time(
+            sc.encrypt_offset_date_time_to_url_component(
+                &date_time,
+                TimestampPrecision::Milliseconds
+            ),
+            TimestampPrecision::Milliseconds
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        time::OffsetDateTime::from_unix_timestamp(-1_700_000_001).unwrap(),
+        sc.decrypt_offset_date_time(
+            &sc.encrypt_offset_date_time(&date_time, TimestampPrecision::Seconds),
+            TimestampPrecision::Seconds
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_f64() {
+    let sc = ShortCrypt::new("magickey");
+
+    for n in [0.0, -0.0, 19.99, -273.15, f64::INFINITY, f64::MIN_POSITIVE, f64::NAN] {
+        assert_eq!(n.to_bits(), sc.decrypt_f64(&sc.encrypt_f64(n)).unwrap().to_bits());
+        assert_eq!(
+            n.to_bits(),
+            sc.decrypt_url_component_to_f64(sc.encrypt_f64_to_url_component(n)).unwrap().to_bits()
+        );
+        assert_eq!(
+            n.to_bits(),
+            sc.decrypt_qr_code_alphanumeric_to_f64(sc.encrypt_f64_to_qr_code_alphanumeric(n))
+                .unwrap()
+                .to_bits()
+        );
+    }
+}
+
+#[cfg(feature = "global")]
+#[test]
+fn test_global() {
+    use short_crypt::global;
+
+    assert!(global::get().is_none());
+
+    global::init("magickey").unwrap();
+
+    assert_eq!(Err(ShortCrypt::new("newkey")), global::init("newkey"));
+    assert_eq!(Some(&ShortCrypt::new("magickey")), global::get());
+
+    assert_eq!("2E87Wx52-Tvo", global::encrypt_url_component("articles"));
+    assert_eq!(b"articles".to_vec(), global::decrypt_url_component("2E87Wx52-Tvo").unwrap());
+    assert_eq!("3BHNNR45XZH8PU", global::encrypt_qr_code_alphanumeric("articles"));
+    assert_eq!(
+        b"articles".to_vec(),
+        global::decrypt_qr_code_alphanumeric("3BHNNR45XZH8PU").unwrap()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_large() {
+    let sc = ShortCrypt::new("magickey");
+
+    let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+
+    let mut encrypted = Vec::new();
+
+    assert_eq!(200_006, sc.encrypt_large(&mut data.as_slice(), &mut encrypted).unwrap());
+    assert_eq!(200_006, encrypted.len());
+
+    let first = sc.encrypt(&data[..ShortCrypt::LARGE_FRAME_LEN]);
+
+    assert_eq!(first.base(), encrypted[0]);
+    assert_eq!(first.body(), &encrypted[1..=ShortCrypt::LARGE_FRAME_LEN]);
+
+    let mut decrypted = Vec::new();
+
+    assert_eq!(200_000, sc.decrypt_large(&mut encrypted.as_slice(), &mut decrypted).unwrap());
+    assert_eq!(data, decrypted);
+
+    let frames_len = 3 * (1 + ShortCrypt::LARGE_FRAME_LEN);
+
+    // cut at a frame boundary, so the final frame is missing
+    let error = sc.decrypt_large(&mut &encrypted[..frames_len], &mut Vec::new()).unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+
+    // cut inside the final frame
+    let error =
+        sc.decrypt_large(&mut &encrypted[..(encrypted.len() - 1)], &mut Vec::new()).unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+
+    // data after the final frame
+    let mut trailing = encrypted.clone();
+
+    trailing.push(0);
+
+    let error = sc.decrypt_large(&mut trailing.as_slice(), &mut Vec::new()).unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+
+    // a plaintext of whole frames ends with an empty final frame
+    let mut encrypted = Vec::new();
+
+    assert_eq!(
+        ShortCrypt::LARGE_FRAME_LEN as u64 + 4,
+        sc.encrypt_large(&mut &data[..ShortCrypt::LARGE_FRAME_LEN], &mut encrypted).unwrap()
+    );
+
+    let mut decrypted = Vec::new();
+
+    sc.decrypt_large(&mut encrypted.as_slice(), &mut decrypted).unwrap();
+
+    assert_eq!(&data[..ShortCrypt::LARGE_FRAME_LEN], decrypted.as_slice());
+
+    let error = sc
+        .decrypt_large(&mut &encrypted[..=ShortCrypt::LARGE_FRAME_LEN], &mut Vec::new())
+        .unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+
+    let mut encrypted = Vec::new();
+
+    assert_eq!(3, sc.encrypt_large(&mut &b""[..], &mut encrypted).unwrap());
+
+    let mut decrypted = Vec::new();
+
+    assert_eq!(0, sc.decrypt_large(&mut encrypted.as_slice(), &mut decrypted).unwrap());
+    assert!(decrypted.is_empty());
+
+    let error = sc.decrypt_large(&mut &b""[..], &mut Vec::new()).unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+
+    let mut encrypted = Vec::new();
+
+    sc.encrypt_large(&mut &b"articles"[..], &mut encrypted).unwrap();
+
+    assert_eq!(&[0x80 | 8, 0, 8, 216, 78, 214, 199, 157, 190, 78, 250], encrypted.as_slice());
+
+    // a short frame without the final flag
+    let error = sc.decrypt_large(&mut &[8, 216][..], &mut Vec::new()).unwrap_err();
+
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+
+    let error = sc.decrypt_large(&mut &[0x80 | 32, 0, 1, 0][..], &mut Vec::new()).unwrap_err();
+
+    assert_eq!(
+        Some(&ShortCryptError::InvalidBase),
+        error.get_ref().and_then(|error| error.downcast_ref::<ShortCryptError>())
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_key_file() {
+    use std::fs;
+
+    use short_crypt::KeyFileError;
+
+    let path = std::env::temp_dir().join(format!("short-crypt-key-{}", std::process::id()));
+
+    fs::write(&path, "magickey\r\n").unwrap();
+
+    assert_eq!(ShortCrypt::new("magickey"), ShortCrypt::from_key_file(&path).unwrap());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        assert!(matches!(
+            ShortCrypt::from_key_file_checked(&path),
+            Err(KeyFileError::InsecurePermissions {
+                mode: 0o644
+            })
+        ));
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    assert_eq!(ShortCrypt::new("magickey"), ShortCrypt::from_key_file_checked(&path).unwrap());
+
+    fs::write(&path, "\n").unwrap();
+
+    assert!(matches!(ShortCrypt::from_key_file(&path), Err(KeyFileError::Empty)));
+
+    fs::remove_file(&path).unwrap();
+
+    assert!(matches!(ShortCrypt::from_key_file(&path), Err(KeyFileError::Io(_))));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_path() {
+    use std::path::Path;
+
+    let sc = ShortCrypt::new("magickey");
+
+    let path = Path::new("downloads/2024/report.pdf");
+
+    assert_eq!(path, sc.decrypt_to_path_buf(sc.encrypt_path(path).unwrap()).unwrap());
+
+    #[cfg(unix)]
+    {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let path = Path::new(OsStr::from_bytes(b"downloads/\xFF\xFE.bin"));
+
+        assert_eq!(path, sc.decrypt_to_path_buf(sc.encrypt_path(path).unwrap()).unwrap());
+    }
+}
+
+#[test]
+fn test_mac() {
+    let sc = ShortCrypt::new("magickey");
+
+    let mac = [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+
+    assert_eq!(mac, sc.decrypt_mac(&sc.encrypt_mac(mac)).unwrap());
+    assert_eq!(mac, sc.decrypt_url_component_to_mac(sc.encrypt_mac_to_url_component(mac)).unwrap());
+
+    let token = sc.encrypt_mac_to_qr_code_alphanumeric(mac);
+
+    assert_eq!(11, token.len());
+    assert_eq!(mac, sc.decrypt_qr_code_alphanumeric_to_mac(token).unwrap());
+
+    assert!(sc.decrypt_qr_code_alphanumeric_to_mac("3BHNNR45XZH8PU").is_err());
+}
+
+#[test]
+fn test_token_map() {
+    use short_crypt::TokenMap;
+
+    let sc = ShortCrypt::new("magickey");
+
+    let mut map = TokenMap::new();
+
+    map.insert("ssid", "home-network");
+    map.insert("channel", "6");
+    map.insert("id", "device-42");
+
+    let decrypted = sc
+        .decrypt_qr_code_alphanumeric_to_token_map(
+            sc.encrypt_token_map_to_qr_code_alphanumeric(&map),
+        )
+        .unwrap();
+
+    assert_eq!(map, decrypted);
+    assert_eq!(Some("6"), decrypted.get("channel"));
+    assert_eq!(vec!["channel", "id", "ssid"], decrypted.iter().map(|(k, _)| k).collect::<Vec<_>>());
+
+    assert_eq!(
+        map,
+        sc.decrypt_url_component_to_token_map(sc.encrypt_token_map_to_url_component(&map)).unwrap()
+    );
+
+    let duplicated = sc.encrypt_fields_to_url_component(&["k", "v", "k", "v"]);
+
+    assert!(sc.decrypt_url_component_to_token_map(duplicated).is_err());
+}
+
+#[test]
+fn test_capability() {
+    use short_crypt::Capability;
+
+    const READ: u64 = 1;
+    const WRITE: u64 = 1 << 1;
+    const ADMIN: u64 = 1 << 63;
+
+    let sc = ShortCrypt::new("magickey");
+
+    let capability = Capability::new(42, READ | WRITE);
+
+    let decrypted = sc
+        .decrypt_url_component_to_capability(sc.encrypt_capability_to_url_component(&capability))
+        .unwrap();
+
+    assert_eq!(capability, decrypted);
+    assert!(decrypted.contains(READ | WRITE));
+    assert!(!decrypted.contains(ADMIN));
+
+    let capability = Capability::new(u64::MAX, ADMIN);
+
+    assert_eq!(capability, sc.decrypt_capability(&sc.encrypt_capability(&capability)).unwrap());
+
+    assert!(sc
+        .decrypt_url_component_to_capability(sc.encrypt_u64_varint_to_url_component(42))
+        .is_err());
+}
+
+#[test]
+fn test_error() {
+    let sc = ShortCrypt::new("magickey");
+
+    assert_eq!(
+        Err(ShortCryptError::InvalidCharacter {
+            index: 4
+        }),
+        sc.decrypt_base58("dBTa0ahCE9t1")
+    );
+    assert_eq!(
+        Err(ShortCryptError::InvalidCharacter {
+            index: 3
+        }),
+        sc.decrypt_url_component("2E8*Wx52-Tvo")
+    );
+    assert_eq!(Some(5), sc.decrypt_qr_code_alphanumeric("3BHNN!45XZH8PU").unwrap_err().index());
+    assert_eq!(Err(ShortCryptError::InvalidLength), sc.decrypt_url_component(""));
+    assert_eq!(None, ShortCryptError::InvalidLength.index());
+    assert_eq!(
+        "The character at index 3 is invalid.",
+        sc.decrypt_url_component("2E8*Wx52-Tvo").unwrap_err().to_string()
+    );
+    assert_eq!(Err(ShortCryptError::InvalidBase), Cipher::new(32, b"articles".to_vec()));
+
+    let error: &'static str = ShortCryptError::Expired.into();
+
+    assert_eq!("The token has expired.", error);
+}
+
+/// Feed every text decoder with random and mutated texts. A panic fails the test, so untrusted input must always end up in a `ShortCryptError`.
+#[test]
+fn test_untrusted_input_never_panics() {
+    fn decrypt_all(sc: &ShortCrypt, s: &str) {
+        let _ = sc.decrypt_url_component(s);
+        let _ = sc.decrypt_qr_code_alphanumeric(s);
+        let _ = sc.decrypt_any(s);
+        let _ = sc.decrypt_base26(s);
+        let _ = sc.decrypt_base32_lowercase(s);
+        let _ = sc.decrypt_base32hex(s);
+        let _ = sc.decrypt_base36(s);
+        let _ = sc.decrypt_base45(s);
+        let _ = sc.decrypt_base58(s);
+        let _ = sc.decrypt_base62(s);
+        let _ = sc.decrypt_base64(s);
+        let _ = sc.decrypt_base85(s);
+        let _ = sc.decrypt_bech32("sc", s);
+        let _ = sc.decrypt_bech32(s, s);
+        let _ = sc.decrypt_code39(s);
+        let _ = sc.decrypt_crockford32(s);
+        let _ = sc.decrypt_custom_alphabet(
+            s,
+            &Alphabet::new("0123456789ABCDEFGHJKMNPQRSTVWXYZ").unwrap(),
+        );
+        let _ = sc.decrypt_dns_label(s);
+        let _ = sc.decrypt_emoji(s);
+        let _ = sc.decrypt_hex(s);
+        let _ = sc.decrypt_luhn_numeric(s);
+        let _ = sc.decrypt_proquint(s);
+        let _ = sc.decrypt_qr_code_numeric(s);
+        let _ = sc.decrypt_qr_code_kanji(s);
+        let _ = sc.decrypt_serial(s, '-');
+        let _ = sc.decrypt_uuid(s);
+        let _ = sc.decrypt_words(s);
+        let _ = sc.decrypt_z_base_32(s);
+        let _ = sc.decrypt_timed(s, 0);
+        let _ = sc.decrypt_cursor(s);
+        let _ = sc.decrypt_url_component_to_capability(s);
+        let _ = sc.decrypt_url_component_to_fields(s);
+        let _ = sc.decrypt_url_component_to_token_map(s);
+        let _ = sc.decrypt_url_component_to_string(s);
+        let _ = sc.decrypt_url_component_to_u64_varint(s);
+        let _ = sc.decrypt_url_component_to_i64(s);
+        let _ = sc.decrypt_url_component_to_u128(s);
+        let _ = sc.decrypt_url_component_to_ipv6(s);
+        let _ = sc.decrypt_url_component_to_mac(s);
+        let _ = KeyRing::new(*sc).decrypt_url_component(s);
+        let _ = KeyedDecoder::new().decrypt_qr_code_alphanumeric(s);
+
+        let mut decryptor = sc.decryptor();
+
+        decryptor.update(s);
+
+        let _ = decryptor.finalize();
+
+        if let Some((base, body)) = s.as_bytes().split_first() {
+            let _ = sc.decrypt_into(*base, body, &mut [0u8; 4]);
+        }
+    }
+
+    // xorshift64, so the inputs are reproducible
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    let mut random = |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        (state % n as u64) as usize
+    };
+
+    let pool: Vec<char> =
+        (' '..='~').chain(['\n', '\u{0}', 'é', '\u{3000}', '\u{FEFF}', '😀']).collect();
+
+    let mut sc = ShortCrypt::new("magickey");
+
+    let seeds = [
+        sc.encrypt_to_url_component("articles"),
+        sc.encrypt_to_qr_code_alphanumeric("articles"),
+        sc.encrypt_to_base45("articles"),
+        sc.encrypt_to_base85("articles"),
+        sc.encrypt_to_emoji("articles"),
+        sc.encrypt_to_words("articles"),
+        sc.encrypt_to_proquint("articles"),
+        sc.encrypt_to_bech32("sc", "articles").unwrap(),
+        sc.encrypt_to_luhn_numeric("articles"),
+        sc.encrypt_to_qr_code_numeric("articles"),
+    ];
+
+    for round in 0..4000 {
+        if round == 2000 {
+            sc.set_ignore_separators(true);
+        }
+
+        let text: String = if round % 2 == 0 {
+            (0..random(32)).map(|_| pool[random(pool.len())]).collect()
+        } else {
+            let mut chars: Vec<char> = seeds[random(seeds.len())].chars().collect();
+
+            for _ in 0..random(4) {
+                let index = random(chars.len() + 1);
+
+                if index < chars.len() && random(2) == 0 {
+                    chars.remove(index);
+                } else {
+                    chars.insert(index, pool[random(pool.len())]);
+                }
+            }
+
+            chars.into_iter().collect()
+        };
+
+        decrypt_all(&sc, &text);
+    }
+}